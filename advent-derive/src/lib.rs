@@ -0,0 +1,218 @@
+//!
+//! `#[derive(AocParse)]`, for parsing a line of puzzle input into a struct or an enum.
+//!
+//! The generated code matches lines with `advent_utils::pattern`, so the attributes are
+//! documented with the `AocParse` trait there.
+//!
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Attribute, Data, DeriveInput, Error, Fields, LitStr, Member, Result};
+
+#[proc_macro_derive(AocParse, attributes(aoc))]
+pub fn derive_aoc_parse(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input).unwrap_or_else(Error::into_compile_error).into()
+}
+
+/// The options given in `#[aoc(...)]` attributes.
+#[derive(Default, Clone)]
+struct Options {
+    format: Option<LitStr>,
+    sep: Option<LitStr>,
+    token: Option<LitStr>,
+}
+
+fn options(attrs: &[Attribute]) -> Result<Options> {
+    let mut options = Options::default();
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("aoc")) {
+        attr.parse_nested_meta(|meta| {
+            let slot = if meta.path.is_ident("format") {
+                &mut options.format
+            } else if meta.path.is_ident("sep") {
+                &mut options.sep
+            } else if meta.path.is_ident("token") {
+                &mut options.token
+            } else {
+                return Err(meta.error("expected `format`, `sep` or `token`"));
+            };
+            *slot = Some(meta.value()?.parse()?);
+            Ok(())
+        })?;
+    }
+    Ok(options)
+}
+
+fn expand(input: &DeriveInput) -> Result<TokenStream2> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let options = options(&input.attrs)?;
+
+    let body = match &input.data {
+        Data::Struct(data) => {
+            if let Some(token) = &options.token {
+                return Err(Error::new(token.span(), "`token` is only used on enum variants"));
+            }
+            let (pattern, order) = pattern(&data.fields, &options)?;
+            let construct = construct(quote!(Self), &order, quote!(line));
+            quote! {
+                let pattern: &'static str = #pattern;
+                ::advent_utils::pattern::match_pattern(pattern, line).and_then(|fields| #construct)
+            }
+        },
+        Data::Enum(data) => {
+            let mut variants = vec![];
+            for variant in &data.variants {
+                let variant_options = options_with_default(&variant.attrs, &options)?;
+                let token = variant_options.token.as_ref().map_or(variant.ident.to_string(), LitStr::value);
+                let (pattern, order) = pattern(&variant.fields, &variant_options)?;
+                let ident = &variant.ident;
+                variants.push((token, pattern, construct(quote!(Self::#ident), &order, quote!(rest))));
+            }
+            // Longer tokens are tried first, so a token can start with another one. If a token
+            // matches but its fields don't, shorter tokens still get a chance, and the longest
+            // token's error is reported only if none of them match.
+            variants.sort_by_key(|(token, _, _)| std::cmp::Reverse(token.len()));
+
+            let all = variants.iter().map(|(token, pattern, _)| format!("{}{}", token, pattern)).collect::<Vec<_>>().join(" | ");
+            let arms = variants.iter().map(|(token, pattern, construct)| quote! {
+                // The token is a whole word, so `turnip` doesn't start with the token `turn`.
+                if let Some(rest) = line.strip_prefix(#token).filter(|rest| rest.is_empty() || rest.starts_with(char::is_whitespace)) {
+                    let rest = rest.trim_start();
+                    let pattern: &'static str = #pattern;
+                    match ::advent_utils::pattern::match_pattern(pattern, rest).and_then(|fields| #construct) {
+                        Ok(value) => return Ok(value),
+                        Err(err) => {
+                            error.get_or_insert(::advent_utils::pattern::PatternError { line: line.to_string(), ..err });
+                        },
+                    }
+                }
+            });
+            quote! {
+                #[allow(unused_mut)]
+                let mut error: ::std::option::Option<::advent_utils::pattern::PatternError> = None;
+                #(#arms)*
+                Err(error.unwrap_or_else(|| ::advent_utils::pattern::PatternError {
+                    line: line.to_string(),
+                    pattern: #all,
+                    message: "no variant's token starts the line".to_string(),
+                }))
+            }
+        },
+        Data::Union(_) => return Err(Error::new(name.span(), "AocParse can't be derived for a union")),
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::advent_utils::pattern::AocParse for #name #ty_generics #where_clause {
+            fn parse_line(line: &str) -> ::std::result::Result<Self, ::advent_utils::pattern::PatternError> {
+                #body
+            }
+        }
+
+        impl #impl_generics ::std::str::FromStr for #name #ty_generics #where_clause {
+            type Err = ::advent_utils::pattern::PatternError;
+
+            fn from_str(line: &str) -> ::std::result::Result<Self, Self::Err> {
+                <Self as ::advent_utils::pattern::AocParse>::parse_line(line)
+            }
+        }
+
+        impl #impl_generics ::advent_utils::pattern::FromField for #name #ty_generics #where_clause {
+            fn from_field(field: ::advent_utils::pattern::Field) -> ::std::result::Result<Self, String> {
+                <Self as ::advent_utils::pattern::AocParse>::parse_line(field.text).map_err(|err| err.to_string())
+            }
+        }
+    })
+}
+
+/// A variant's options, falling back to the enum's for the separator.
+fn options_with_default(attrs: &[Attribute], default: &Options) -> Result<Options> {
+    let mut options = options(attrs)?;
+    if options.format.is_none() && options.sep.is_none() {
+        options.sep = default.sep.clone();
+    }
+    Ok(options)
+}
+
+fn member_name(member: &Member) -> String {
+    match member {
+        Member::Named(ident) => ident.to_string(),
+        Member::Unnamed(index) => index.index.to_string(),
+    }
+}
+
+/// Builds the `parse_pattern!` style pattern for some fields, along with the fields in the
+/// order they appear in it.
+///
+/// Without a `format` the fields are joined by `sep`, or a space. A field's own `sep` says
+/// how to split it when it is a `Vec`.
+fn pattern(fields: &Fields, options: &Options) -> Result<(String, Vec<Member>)> {
+    let mut members = vec![];
+    for (i, field) in fields.iter().enumerate() {
+        let member = field.ident.clone().map_or(Member::Unnamed(i.into()), Member::Named);
+        members.push((member, options_for_field(&field.attrs)?));
+    }
+    let placeholder = |sep: &Option<LitStr>| format!("{{{}}}", sep.as_ref().map(LitStr::value).unwrap_or_default());
+
+    let Some(format) = &options.format else {
+        let sep = options.sep.as_ref().map_or(" ".to_string(), LitStr::value);
+        let pattern = members.iter().map(|(_, sep)| placeholder(sep)).collect::<Vec<_>>().join(&sep.replace('{', "{{").replace('}', "}}"));
+        return Ok((pattern, members.into_iter().map(|(member, _)| member).collect()));
+    };
+
+    let text = format.value();
+    let mut pattern = String::new();
+    let mut order: Vec<Member> = vec![];
+    let mut rest = text.as_str();
+    while let Some(c) = rest.chars().next() {
+        if rest.starts_with("{{") || rest.starts_with("}}") {
+            pattern.push_str(&rest[..2]);
+            rest = &rest[2..];
+        } else if c == '{' {
+            let end = rest.find('}').ok_or_else(|| Error::new(format.span(), "format has an unclosed `{`"))?;
+            let name = &rest[1..end];
+            let (member, sep) = members
+                .iter()
+                .find(|(member, _)| member_name(member) == name)
+                .ok_or_else(|| Error::new(format.span(), format!("format names `{}`, which is not a field", name)))?;
+            if order.iter().any(|used| member_name(used) == name) {
+                return Err(Error::new(format.span(), format!("format uses `{}` more than once", name)));
+            }
+            pattern.push_str(&placeholder(sep));
+            order.push(member.clone());
+            rest = &rest[end + 1..];
+        } else {
+            pattern.push(c);
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+
+    if let Some((member, _)) = members.iter().find(|(member, _)| !order.iter().any(|used| member_name(used) == member_name(member))) {
+        return Err(Error::new(format.span(), format!("format is missing field `{}`", member_name(member))));
+    }
+    Ok((pattern, order))
+}
+
+/// A field's separator, the only option fields take.
+fn options_for_field(attrs: &[Attribute]) -> Result<Option<LitStr>> {
+    let options = options(attrs)?;
+    if let Some(other) = options.format.as_ref().or(options.token.as_ref()) {
+        return Err(Error::new(other.span(), "fields only take a `sep`"));
+    }
+    Ok(options.sep)
+}
+
+/// Builds `path` from the matched `fields`, converting each with `parse_field`.
+fn construct(path: TokenStream2, order: &[Member], line: TokenStream2) -> TokenStream2 {
+    if order.is_empty() {
+        return quote!({ let _ = fields; Ok(#path {}) });
+    }
+    let values = order.iter().enumerate().map(|(i, member)| quote! {
+        #member: ::advent_utils::pattern::parse_field(pattern, #line, #i, fields.next().unwrap())?
+    });
+    quote!({
+        let mut fields = fields.into_iter();
+        Ok(#path { #(#values),* })
+    })
+}
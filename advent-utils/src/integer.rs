@@ -0,0 +1,47 @@
+//!
+//! Traits over the primitive integer types, so parsing can be written once for all of them.
+//!
+
+use std::fmt::Debug;
+
+pub trait Integer: Copy + PartialEq + PartialOrd + Debug {
+    const ZERO: Self;
+    const SIGNED: bool;
+
+    /// Converts a single digit in any radix up to 36, which fits in every integer type.
+    fn from_digit(digit: u8) -> Self;
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+}
+
+pub trait Unsigned: Integer {}
+pub trait Signed: Integer {}
+
+macro_rules! impl_integer {
+    ($($t:ty)*) => ($(
+        impl Integer for $t {
+            const ZERO: Self = 0;
+            const SIGNED: bool = <$t>::MIN != 0;
+
+            #[inline]
+            fn from_digit(digit: u8) -> Self { digit as $t }
+            #[inline]
+            fn checked_add(self, other: Self) -> Option<Self> { <$t>::checked_add(self, other) }
+            #[inline]
+            fn checked_sub(self, other: Self) -> Option<Self> { <$t>::checked_sub(self, other) }
+            #[inline]
+            fn checked_mul(self, other: Self) -> Option<Self> { <$t>::checked_mul(self, other) }
+        }
+    )*)
+}
+
+macro_rules! impl_empty_trait {
+    ($name:ident for $($t:ty)*) => ($(
+        impl $name for $t {}
+    )*)
+}
+
+impl_integer!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+impl_empty_trait!(Unsigned for u8 u16 u32 u64 u128 usize);
+impl_empty_trait!(Signed for i8 i16 i32 i64 i128 isize);
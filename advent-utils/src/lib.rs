@@ -0,0 +1,33 @@
+//!
+//! Helpers for writing Advent of Code solutions: parsing puzzle input, a little maths,
+//! timing, and a framework for defining solvers.
+//!
+//! Everything is enabled by default. The optional parts are behind these features:
+//!
+//! * `parsing` - `parse`, for pulling numbers and grids out of input text, and `pattern`
+//!   with `parse_pattern!` and `#[derive(AocParse)]` for splitting lines into typed fields,
+//!   and `worksheet` for column-aligned text
+//! * `math` - `math`
+//! * `timing` - `performance`, for timing and formatting durations
+//! * `solver` - `solver` and `define_solver!`, which needs `timing` and `parsing`
+//!
+//! `log` and `integer` are always included.
+//!
+
+// Lets `#[derive(AocParse)]`, which refers to `::advent_utils`, be used within this crate.
+extern crate self as advent_utils;
+
+pub mod log;
+pub mod integer;
+#[cfg(feature = "parsing")]
+pub mod parse;
+#[cfg(feature = "parsing")]
+pub mod pattern;
+#[cfg(feature = "parsing")]
+pub mod worksheet;
+#[cfg(feature = "math")]
+pub mod math;
+#[cfg(feature = "timing")]
+pub mod performance;
+#[cfg(feature = "solver")]
+pub mod solver;
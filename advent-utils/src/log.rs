@@ -0,0 +1,112 @@
+//!
+//! Output levels, so runs can print anything from just the answers to solver internals.
+//!
+//! The level is global and set once from the command line.
+//!
+
+use std::fmt;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    /// Only the answers.
+    Quiet,
+    /// Banners, timings and answers.
+    Normal,
+    /// Also solver debug output, such as grids printed with `debug::print_vec_2d`.
+    Verbose,
+    /// Everything, including trace events from inside solvers.
+    Trace,
+}
+
+static LEVEL: AtomicU8 = AtomicU8::new(Level::Normal as u8);
+
+pub fn set_level(level: Level) {
+    LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+pub fn level() -> Level {
+    match LEVEL.load(Ordering::Relaxed) {
+        0 => Level::Quiet,
+        1 => Level::Normal,
+        2 => Level::Verbose,
+        _ => Level::Trace,
+    }
+}
+
+/// Whether output at `level` should be printed.
+pub fn enabled(level: Level) -> bool {
+    level <= self::level()
+}
+
+/// Prints an answer, labelled unless only answers are being printed.
+pub fn answer(answer: &str) {
+    if level() == Level::Quiet {
+        println!("{}", answer);
+    } else {
+        println!("Solution: {}", answer);
+    }
+}
+
+impl FromStr for Level {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "quiet" => Ok(Level::Quiet),
+            "normal" => Ok(Level::Normal),
+            "verbose" => Ok(Level::Verbose),
+            "trace" => Ok(Level::Trace),
+            level => Err(format!("Log level should be quiet, normal, verbose or trace, got {}", level)),
+        }
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Level::Quiet => "quiet",
+            Level::Normal => "normal",
+            Level::Verbose => "verbose",
+            Level::Trace => "trace",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Prints a line if the given level is enabled.
+///
+/// ```
+/// use advent_utils::log;
+/// use advent_utils::log::Level;
+///
+/// log!(Level::Verbose, "Grid is {}x{}", 10, 20);
+/// ```
+#[macro_export]
+macro_rules! log {
+    ($level:expr, $($arg:tt)*) => {
+        if $crate::log::enabled($level) {
+            println!($($arg)*);
+        }
+    };
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn levels_are_ordered() {
+        assert!(Level::Quiet < Level::Normal);
+        assert!(Level::Normal < Level::Verbose);
+        assert!(Level::Verbose < Level::Trace);
+    }
+
+    #[test]
+    fn parse_level() {
+        assert_eq!("verbose".parse::<Level>(), Ok(Level::Verbose));
+        assert!("loud".parse::<Level>().is_err());
+    }
+}
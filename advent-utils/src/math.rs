@@ -1,3 +1,11 @@
+/// Whether `n` is prime, by trial division.
+///
+/// ```
+/// use advent_utils::math::is_prime;
+///
+/// assert!(is_prime(7873));
+/// assert!(!is_prime(4890));
+/// ```
 pub fn is_prime(n: u64) -> bool {
     if n <= 1 {
         return false; 
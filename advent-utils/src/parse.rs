@@ -0,0 +1,1156 @@
+//!
+//! Tools for parsing input text into formats commonly used in Advent of Code solutions.
+//!
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::marker::PhantomData;
+use std::str::FromStr;
+use crate::integer::{Integer, Signed, Unsigned};
+use crate::pattern::AocParse;
+
+pub trait AocParseExt<'a> {
+    fn as_unsigned_iter<T: Unsigned>(&'a self) -> IntParser<'a, T>;
+    fn as_signed_iter<T: Signed>(&'a self) -> IntParser<'a, T>;
+    /// Like `as_unsigned_iter`, but reports where each integer is and returns overflow as an error.
+    fn try_unsigned_iter<T: Unsigned>(&'a self) -> TryIntParser<'a, T>;
+    /// Like `as_signed_iter`, but reports where each integer is and returns overflow as an error.
+    fn try_signed_iter<T: Signed>(&'a self) -> TryIntParser<'a, T>;
+    /// Iterates through integers written in `radix`, skipping a `0x` prefix for hexadecimal
+    /// and `0b` for binary. Signed types also take a leading `-`.
+    fn as_radix_iter<T: Integer>(&'a self, radix: u8) -> IntParser<'a, T>;
+    /// Iterates through every decimal digit on its own, skipping anything else.
+    fn as_digit_iter<T: Integer>(&'a self) -> DigitParser<'a, T>;
+    /// Iterates through decimal numbers, with optional fractional part and exponent.
+    fn as_float_iter(&'a self) -> FloatParser<'a>;
+    /// Iterates through the blocks of lines separated by blank lines.
+    fn sections(&'a self) -> Sections<'a>;
+    /// Parses each non-blank line as a record, pointing at the first line which doesn't parse.
+    fn parse_records<T: AocParse>(&'a self) -> Result<Vec<T>, Box<Diagnostic>>;
+    fn to_char_grid(&'a self) -> Vec<Vec<char>>;
+}
+
+impl <'a>AocParseExt<'a> for &str {
+    fn as_unsigned_iter<T: Unsigned>(&'a self) -> IntParser<'a, T> {
+        IntParser::new(self, IntParserType::Unsigned)
+    }
+    fn as_signed_iter<T: Signed>(&'a self) -> IntParser<'a, T> {
+        IntParser::new(self, IntParserType::Signed)
+    }
+    fn try_unsigned_iter<T: Unsigned>(&'a self) -> TryIntParser<'a, T> {
+        TryIntParser::new(IntParser::new(self, IntParserType::Unsigned))
+    }
+    fn try_signed_iter<T: Signed>(&'a self) -> TryIntParser<'a, T> {
+        TryIntParser::new(IntParser::new(self, IntParserType::Signed))
+    }
+    fn as_radix_iter<T: Integer>(&'a self, radix: u8) -> IntParser<'a, T> {
+        let parser_type = if T::SIGNED { IntParserType::Signed } else { IntParserType::Unsigned };
+        IntParser::with_radix(self, parser_type, radix)
+    }
+    fn as_digit_iter<T: Integer>(&'a self) -> DigitParser<'a, T> {
+        DigitParser { input: self.as_bytes().iter(), _marker: PhantomData }
+    }
+    fn as_float_iter(&'a self) -> FloatParser<'a> {
+        FloatParser { input: self, offset: 0 }
+    }
+    fn sections(&'a self) -> Sections<'a> {
+        Sections { input: self, offset: 0, index: 0, line: 1 }
+    }
+    fn parse_records<T: AocParse>(&'a self) -> Result<Vec<T>, Box<Diagnostic>> {
+        self.lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| {
+                T::parse_line(line).map_err(|err| {
+                    let diagnostic = Diagnostic::whole_line(self, i + 1, err.message)
+                        .with_hint(format!("expected a line matching {:?}", err.pattern));
+                    Box::new(diagnostic)
+                })
+            })
+            .collect()
+    }
+    fn to_char_grid(&'a self) -> Vec<Vec<char>> {
+        self
+            .lines()
+            .into_iter()
+            .map(|line| line.chars().collect())
+            .collect()
+    }
+}
+
+/// A parser which allows for iterating through all unsigned integers in a &str.
+///
+/// Values are built up directly from the bytes of the input, so no allocation is made
+/// per number. Anything which isn't a digit separates numbers, except that a signed
+/// parser treats a `-` seen before the digits start as making the number negative.
+/// A number too large for `T` panics.
+///
+/// # Examples
+///
+/// ```
+/// use advent_utils::parse::{AocParseExt, IntParser};
+///
+/// let mut it: IntParser<u32> = "1,2,a b c,3".as_unsigned_iter();
+/// assert_eq!(it.next(), Some(1));
+/// assert_eq!(it.next(), Some(2));
+/// assert_eq!(it.next(), Some(3));
+/// assert_eq!(it.next(), None);
+/// ```
+/// 
+/// ```
+/// use advent_utils::parse::{AocParseExt, IntParser};
+///
+/// let mut it: IntParser<i32> = "1,-2,a b c,3".as_signed_iter();
+/// assert_eq!(it.next(), Some(1));
+/// assert_eq!(it.next(), Some(-2));
+/// assert_eq!(it.next(), Some(3));
+/// assert_eq!(it.next(), None);
+/// ```
+pub struct IntParser<'a, T> {
+    parser_type: IntParserType,
+    radix: u8,
+    input: &'a [u8],
+    offset: usize,
+    _marker: PhantomData<T>,
+}
+
+#[derive(PartialEq)]
+pub enum IntParserType {
+    Signed,
+    Unsigned,
+}
+
+
+impl<'a, T> IntParser<'a, T> {
+    fn new(input: &'a str, parser_type: IntParserType) -> Self {
+        Self::with_radix(input, parser_type, 10)
+    }
+
+    fn with_radix(input: &'a str, parser_type: IntParserType, radix: u8) -> Self {
+        assert!((2..=36).contains(&radix), "radix should be between 2 and 36, got {}", radix);
+        Self { input: input.as_bytes(), offset: 0, parser_type, radix, _marker: PhantomData }
+    }
+}
+
+impl<'a, T: Integer> IntParser<'a, T> {
+    /// Finds the next integer, returning it with the byte offset it starts at, or the
+    /// offset of an integer which overflows `T`.
+    fn next_integer(&mut self) -> Option<Result<(T, usize), usize>> {
+        let mut value = Some(T::ZERO);
+        let mut start = None;
+        let mut negative = false;
+        let radix = T::from_digit(self.radix);
+
+        while let Some(&b) = self.input.get(self.offset) {
+            if let Some(digit) = digit_value(b, self.radix) {
+                if start.is_none() {
+                    let signed = negative && self.offset > 0 && self.input[self.offset - 1] == b'-';
+                    start = Some(if signed { self.offset - 1 } else { self.offset });
+                    if self.at_prefix() {
+                        self.offset += 2;
+                        continue;
+                    }
+                }
+                // Negative numbers are built up below zero, so the type's minimum can be parsed.
+                let digit = T::from_digit(digit);
+                let shifted = value.and_then(|v| v.checked_mul(radix));
+                value = if negative {
+                    shifted.and_then(|v| v.checked_sub(digit))
+                } else {
+                    shifted.and_then(|v| v.checked_add(digit))
+                };
+            } else if start.is_some() {
+                break;
+            } else if b == b'-' && self.parser_type == IntParserType::Signed {
+                negative = true;
+            }
+            self.offset += 1;
+        }
+        // Step over the separator, as it can't be the start of the next number.
+        self.offset += 1;
+
+        let start = start?;
+        Some(value.map(|value| (value, start)).ok_or(start))
+    }
+
+    /// Whether the parser is at a `0x` or `0b` prefix matching its radix, followed by a digit.
+    fn at_prefix(&self) -> bool {
+        let prefix = match self.radix {
+            16 => b'x',
+            2 => b'b',
+            _ => return false,
+        };
+        let next = |i: usize| self.input.get(self.offset + i).copied();
+        next(0) == Some(b'0')
+            && next(1).map(|b| b.to_ascii_lowercase()) == Some(prefix)
+            && next(2).and_then(|b| digit_value(b, self.radix)).is_some()
+    }
+}
+
+/// The value of an ASCII digit or letter in `radix`, if it is one.
+#[inline]
+fn digit_value(b: u8, radix: u8) -> Option<u8> {
+    let value = match b {
+        b'0'..=b'9' => b - b'0',
+        b'a'..=b'z' => b - b'a' + 10,
+        b'A'..=b'Z' => b - b'A' + 10,
+        _ => return None,
+    };
+    (value < radix).then_some(value)
+}
+
+impl<'a, T: Integer> Iterator for IntParser<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let parsed = self.next_integer()?;
+        Some(parsed.unwrap_or_else(|_| panic!("integer in input should fit in {}", std::any::type_name::<T>())).0)
+    }
+}
+
+/// A parser which yields each decimal digit in a &str as its own value.
+///
+/// ```
+/// use advent_utils::parse::AocParseExt;
+///
+/// let digits: Vec<u8> = "98 7\n6".as_digit_iter().collect();
+/// assert_eq!(digits, vec![9, 8, 7, 6]);
+/// ```
+pub struct DigitParser<'a, T> {
+    input: std::slice::Iter<'a, u8>,
+    _marker: PhantomData<T>,
+}
+
+impl<'a, T: Integer> Iterator for DigitParser<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.input.find(|b| b.is_ascii_digit()).map(|b| T::from_digit(b - b'0'))
+    }
+}
+
+/// A parser which iterates through the decimal numbers in a &str, such as `-1.5` or `3e-2`.
+///
+/// A number starts with a digit, taking a `-` directly before it. A `.` or an exponent is
+/// only part of the number when followed by a digit.
+///
+/// ```
+/// use advent_utils::parse::AocParseExt;
+///
+/// let values: Vec<f64> = "x=1.5, y=-2, z=6.02E23 w=3.".as_float_iter().collect();
+/// assert_eq!(values, vec![1.5, -2.0, 6.02e23, 3.0]);
+/// ```
+pub struct FloatParser<'a> {
+    input: &'a str,
+    offset: usize,
+}
+
+impl<'a> FloatParser<'a> {
+    fn digits_from(&self, mut i: usize) -> usize {
+        let bytes = self.input.as_bytes();
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        i
+    }
+}
+
+impl<'a> Iterator for FloatParser<'a> {
+    type Item = f64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let bytes = self.input.as_bytes();
+        let digit_at = |i: usize| bytes.get(i).is_some_and(u8::is_ascii_digit);
+
+        let first_digit = (self.offset..bytes.len()).find(|&i| digit_at(i))?;
+        // A `-` right after a number separates a range, like `1.5-2.5`, as with `IntParser`.
+        let signed = first_digit > 0
+            && bytes[first_digit - 1] == b'-'
+            && (first_digit == 1 || !(bytes[first_digit - 2].is_ascii_digit() || bytes[first_digit - 2] == b'.'));
+        let start = if signed { first_digit - 1 } else { first_digit };
+
+        let mut end = self.digits_from(first_digit);
+        if bytes.get(end) == Some(&b'.') && digit_at(end + 1) {
+            end = self.digits_from(end + 1);
+        }
+        if matches!(bytes.get(end), Some(b'e' | b'E')) {
+            let exponent = if matches!(bytes.get(end + 1), Some(b'+' | b'-')) { end + 2 } else { end + 1 };
+            if digit_at(exponent) {
+                end = self.digits_from(exponent);
+            }
+        }
+
+        self.offset = end;
+        Some(self.input[start..end].parse().expect("number should be a valid float"))
+    }
+}
+
+/// An iterator over the sections of an input, which are separated by one or more blank lines.
+///
+/// Lines holding only whitespace count as blank, and `\r\n` line endings are handled.
+///
+/// # Examples
+///
+/// ```
+/// use advent_utils::parse::AocParseExt;
+///
+/// let input = "3-5\r\n10-14\r\n  \r\n1\r\n5\r\n";
+/// let [ranges, ids] = input.sections().exactly::<2>().unwrap();
+/// assert_eq!(ranges.text.lines().collect::<Vec<_>>(), vec!["3-5", "10-14"]);
+///
+/// let ids: Vec<u32> = ids.parse(|text| text.lines().map(str::parse).collect()).unwrap();
+/// assert_eq!(ids, vec![1, 5]);
+/// ```
+pub struct Sections<'a> {
+    input: &'a str,
+    offset: usize,
+    index: usize,
+    /// The line at `offset`, counting from 1.
+    line: usize,
+}
+
+/// A block of lines from an input, along with its position among the input's sections.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Section<'a> {
+    /// The section's position, counting from 0.
+    pub index: usize,
+    /// The line of the input the section starts on, counting from 1.
+    pub line: usize,
+    pub text: &'a str,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SectionError {
+    WrongCount { expected: usize, found: usize },
+    /// A section's parser failed. Sections are numbered from 1 in the message.
+    Invalid { index: usize, message: String },
+}
+
+impl fmt::Display for SectionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SectionError::WrongCount { expected, found } => write!(f, "expected exactly {} sections, found {}", expected, found),
+            SectionError::Invalid { index, message } => write!(f, "section {}: {}", index + 1, message),
+        }
+    }
+}
+
+impl Error for SectionError {}
+
+impl<'a> Sections<'a> {
+    /// Returns the sections, or an error unless there are exactly `N` of them.
+    pub fn exactly<const N: usize>(self) -> Result<[Section<'a>; N], SectionError> {
+        let sections: Vec<_> = self.collect();
+        let found = sections.len();
+        sections.try_into().map_err(|_| SectionError::WrongCount { expected: N, found })
+    }
+
+    /// The line starting at `offset` without its line ending, and the offset of the next line.
+    fn line_at(&self, offset: usize) -> (&'a str, usize) {
+        let rest = &self.input[offset..];
+        let (line, next) = match rest.find('\n') {
+            Some(end) => (&rest[..end], offset + end + 1),
+            None => (rest, self.input.len()),
+        };
+        (line.strip_suffix('\r').unwrap_or(line), next)
+    }
+}
+
+impl<'a> Iterator for Sections<'a> {
+    type Item = Section<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.offset < self.input.len() && self.line_at(self.offset).0.trim().is_empty() {
+            self.offset = self.line_at(self.offset).1;
+            self.line += 1;
+        }
+        if self.offset >= self.input.len() {
+            return None;
+        }
+
+        let (start, line) = (self.offset, self.line);
+        let mut end = start;
+        while self.offset < self.input.len() {
+            let (line, next) = self.line_at(self.offset);
+            if line.trim().is_empty() {
+                break;
+            }
+            end = self.offset + line.len();
+            self.offset = next;
+            self.line += 1;
+        }
+
+        let section = Section { index: self.index, line, text: &self.input[start..end] };
+        self.index += 1;
+        Some(section)
+    }
+}
+
+impl<'a> Section<'a> {
+    /// Parses the section's text, labelling any error with the section's index.
+    pub fn parse<T, E: fmt::Display>(&self, parser: impl FnOnce(&'a str) -> Result<T, E>) -> Result<T, SectionError> {
+        parser(self.text).map_err(|err| SectionError::Invalid { index: self.index, message: err.to_string() })
+    }
+
+    /// Like `AocParseExt::parse_records`, but with the line numbers of the whole input.
+    pub fn parse_records<T: AocParse>(&self) -> Result<Vec<T>, Box<Diagnostic>> {
+        self.text.parse_records().map_err(|mut diagnostic| {
+            diagnostic.line += self.line - 1;
+            diagnostic
+        })
+    }
+}
+
+/// An integer found by a `TryIntParser`, with where it was found. Lines and columns count from 1.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Parsed<T> {
+    pub value: T,
+    pub offset: usize,
+    pub line: usize,
+    pub column: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseError {
+    /// An integer was too large for the type being parsed.
+    Overflow { offset: usize, line: usize, column: usize, type_name: &'static str },
+    /// The input didn't contain the expected number of integers.
+    WrongCount { expected: usize, found: usize },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Overflow { line, column, type_name, .. } => {
+                write!(f, "integer at line {}, column {} does not fit in {}", line, column, type_name)
+            },
+            ParseError::WrongCount { expected, found } => write!(f, "expected exactly {} integers, found {}", expected, found),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// A parser which iterates through the integers in a &str like `IntParser`, but yields
+/// their positions along with them and returns an error for integers which overflow.
+///
+/// # Examples
+///
+/// ```
+/// use advent_utils::parse::{AocParseExt, ParseError};
+///
+/// let mut it = "1\n-23 99999".try_signed_iter::<i16>();
+/// let parsed = it.next().unwrap().unwrap();
+/// assert_eq!((parsed.value, parsed.line, parsed.column), (1, 1, 1));
+/// let parsed = it.next().unwrap().unwrap();
+/// assert_eq!((parsed.value, parsed.offset, parsed.line, parsed.column), (-23, 2, 2, 1));
+/// assert!(matches!(it.next(), Some(Err(ParseError::Overflow { line: 2, column: 5, .. }))));
+/// assert_eq!(it.next(), None);
+/// ```
+pub struct TryIntParser<'a, T> {
+    parser: IntParser<'a, T>,
+    line: usize,
+    line_start: usize,
+    counted_to: usize,
+}
+
+impl<'a, T: Integer> TryIntParser<'a, T> {
+    fn new(parser: IntParser<'a, T>) -> Self {
+        Self { parser, line: 1, line_start: 0, counted_to: 0 }
+    }
+
+    /// Returns the integers, or an error unless there are exactly `N` of them.
+    ///
+    /// ```
+    /// use advent_utils::parse::{AocParseExt, ParseError};
+    ///
+    /// assert_eq!("L68".try_unsigned_iter::<u32>().exactly::<1>(), Ok([68]));
+    /// assert_eq!("1 2".try_unsigned_iter::<u32>().exactly::<1>(), Err(ParseError::WrongCount { expected: 1, found: 2 }));
+    /// ```
+    pub fn exactly<const N: usize>(self) -> Result<[T; N], ParseError> {
+        let mut values = [T::ZERO; N];
+        let mut found = 0;
+        for parsed in self {
+            if let Some(value) = values.get_mut(found) {
+                *value = parsed?.value;
+            }
+            found += 1;
+        }
+        if found != N {
+            return Err(ParseError::WrongCount { expected: N, found });
+        }
+        Ok(values)
+    }
+
+    /// Moves the line count up to `offset`.
+    fn advance_to(&mut self, offset: usize) {
+        for (i, &b) in self.parser.input[self.counted_to..offset].iter().enumerate() {
+            if b == b'\n' {
+                self.line += 1;
+                self.line_start = self.counted_to + i + 1;
+            }
+        }
+        self.counted_to = offset;
+    }
+}
+
+impl<'a, T: Integer> Iterator for TryIntParser<'a, T> {
+    type Item = Result<Parsed<T>, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let scanned = self.parser.next_integer()?;
+        let offset = match scanned {
+            Ok((_, offset)) | Err(offset) => offset,
+        };
+        self.advance_to(offset);
+        let (line, column) = (self.line, offset - self.line_start + 1);
+
+        Some(match scanned {
+            Ok((value, _)) => Ok(Parsed { value, offset, line, column }),
+            Err(_) => Err(ParseError::Overflow { offset, line, column, type_name: std::any::type_name::<T>() }),
+        })
+    }
+}
+
+
+/// A problem with a puzzle input, pointing at where in the input it is.
+///
+/// Solvers return these, boxed, from a fallible preprocess, and the solver framework fills
+/// in the year and day. Lines and columns count from 1, with columns counted in characters.
+///
+/// # Examples
+///
+/// ```
+/// use advent_utils::parse::Diagnostic;
+///
+/// let input = "S..\n.x.\n";
+/// let diagnostic = Diagnostic::at(input, 5, "Unexpected char 'x'")
+///     .with_hint("the manifold should only contain 'S', '^' and '.'")
+///     .for_day("2025", "07");
+/// assert_eq!((diagnostic.line, diagnostic.column), (2, 2));
+/// assert_eq!(diagnostic.to_string(), "\
+/// error: Unexpected char 'x'
+///  --> 2025 day 07, line 2, column 2
+///   |
+/// 2 | .x.
+///   |  ^
+///   = hint: the manifold should only contain 'S', '^' and '.'");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+    pub year: Option<String>,
+    pub day: Option<String>,
+    pub message: String,
+    pub line: usize,
+    pub column: usize,
+    /// How many characters to underline, starting at the column.
+    pub width: usize,
+    pub source_line: String,
+    pub hint: Option<String>,
+}
+
+impl Diagnostic {
+    /// A diagnostic for the character at byte `offset` of `input`.
+    pub fn at(input: &str, offset: usize, message: impl Into<String>) -> Self {
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let line = before.matches('\n').count() + 1;
+        let column = before[line_start..].chars().count() + 1;
+        Self::at_line(input, line, column, message)
+    }
+
+    /// A diagnostic for the character at `line` and `column` of `input`, eg. from a grid position.
+    pub fn at_line(input: &str, line: usize, column: usize, message: impl Into<String>) -> Self {
+        let source_line = input.lines().nth(line.saturating_sub(1)).unwrap_or_default().to_string();
+        Diagnostic { year: None, day: None, message: message.into(), line, column, width: 1, source_line, hint: None }
+    }
+
+    /// A diagnostic underlining all of `line`, for when a line is wrong as a whole.
+    pub fn whole_line(input: &str, line: usize, message: impl Into<String>) -> Self {
+        let diagnostic = Self::at_line(input, line, 1, message);
+        let width = diagnostic.source_line.chars().count();
+        diagnostic.with_width(width)
+    }
+
+    pub fn with_width(mut self, width: usize) -> Self {
+        self.width = width.max(1);
+        self
+    }
+
+    pub fn with_hint(mut self, hint: impl Into<String>) -> Self {
+        self.hint = Some(hint.into());
+        self
+    }
+
+    /// Records which puzzle the input was for.
+    pub fn for_day(mut self, year: &str, day: &str) -> Self {
+        self.year = Some(year.to_string());
+        self.day = Some(day.to_string());
+        self
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let gutter = " ".repeat(self.line.to_string().len());
+        writeln!(f, "error: {}", self.message)?;
+        write!(f, "{}--> ", gutter)?;
+        if let (Some(year), Some(day)) = (&self.year, &self.day) {
+            write!(f, "{} day {}, ", year, day)?;
+        }
+        writeln!(f, "line {}, column {}", self.line, self.column)?;
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", self.line, self.source_line)?;
+        write!(f, "{} | {}{}", gutter, " ".repeat(self.column.saturating_sub(1)), "^".repeat(self.width))?;
+        if let Some(hint) = &self.hint {
+            write!(f, "\n{} = hint: {}", gutter, hint)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for Diagnostic {}
+
+
+/// The brackets around a `Token::Group`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Delimiter {
+    /// `[...]`
+    Bracket,
+    /// `(...)`
+    Paren,
+    /// `{...}`
+    Brace,
+    /// `<...>`
+    Angle,
+}
+
+impl Delimiter {
+    fn from_open(open: u8) -> Option<Self> {
+        match open {
+            b'[' => Some(Delimiter::Bracket),
+            b'(' => Some(Delimiter::Paren),
+            b'{' => Some(Delimiter::Brace),
+            b'<' => Some(Delimiter::Angle),
+            _ => None,
+        }
+    }
+
+    pub fn open(self) -> char {
+        match self {
+            Delimiter::Bracket => '[',
+            Delimiter::Paren => '(',
+            Delimiter::Brace => '{',
+            Delimiter::Angle => '<',
+        }
+    }
+
+    pub fn close(self) -> char {
+        match self {
+            Delimiter::Bracket => ']',
+            Delimiter::Paren => ')',
+            Delimiter::Brace => '}',
+            Delimiter::Angle => '>',
+        }
+    }
+}
+
+/// A bare word, or a group of tokens inside brackets, from `tokenize`.
+///
+/// Inside a group, tokens are separated by commas or whitespace, so `(1,3)` and `(1 3)`
+/// both give two words.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Token<'a> {
+    Word(&'a str),
+    Group { delimiter: Delimiter, items: Vec<Token<'a>> },
+}
+
+impl<'a> Token<'a> {
+    pub fn word(&self) -> Option<&'a str> {
+        match self {
+            Token::Word(word) => Some(word),
+            Token::Group { .. } => None,
+        }
+    }
+
+    /// Parses a word, eg. as a number. `None` for a group or a word which doesn't parse.
+    pub fn parse<T: FromStr>(&self) -> Option<T> {
+        self.word().and_then(|word| word.parse().ok())
+    }
+}
+
+impl fmt::Display for Token<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Token::Word(word) => write!(f, "{}", word),
+            Token::Group { delimiter, items } => {
+                write!(f, "{}", delimiter.open())?;
+                for (i, item) in items.iter().enumerate() {
+                    write!(f, "{}{}", if i == 0 { "" } else { "," }, item)?;
+                }
+                write!(f, "{}", delimiter.close())
+            },
+        }
+    }
+}
+
+/// Splits `line` into words and bracketed groups, eg. `[.##.] (3) (1,3) {3,5,4,7}`.
+///
+/// Commas and whitespace separate tokens. `[]`, `()`, `{}` and `<>` always delimit groups,
+/// so they can't appear in words, and an unclosed or mismatched bracket is an error.
+///
+/// # Examples
+///
+/// ```
+/// use advent_utils::parse::{tokenize, Delimiter, Token};
+///
+/// let tokens = tokenize("[.#] (1,3) on").unwrap();
+/// assert_eq!(tokens[0], Token::Group { delimiter: Delimiter::Bracket, items: vec![Token::Word(".#")] });
+/// let Token::Group { delimiter: Delimiter::Paren, items } = &tokens[1] else { panic!() };
+/// assert_eq!(items.iter().map(|item| item.parse().unwrap()).collect::<Vec<u32>>(), vec![1, 3]);
+/// assert_eq!(tokens[2].word(), Some("on"));
+///
+/// let err = tokenize("(1, [2)").unwrap_err();
+/// assert_eq!((err.message.as_str(), err.column), ("Expected ']' but found ')'", 7));
+/// ```
+pub fn tokenize(line: &str) -> Result<Vec<Token<'_>>, Box<Diagnostic>> {
+    Lexer { input: line, offset: 0, end: line.len() }.tokens(None)
+}
+
+/// Tokenizes each line of `input` with `tokenize`, pointing diagnostics at the right line.
+pub fn tokenize_lines(input: &str) -> Result<Vec<Vec<Token<'_>>>, Box<Diagnostic>> {
+    let mut offset = 0;
+    let mut lines = vec![];
+    for line in input.split_inclusive('\n') {
+        let end = offset + line.trim_end_matches(['\n', '\r']).len();
+        lines.push(Lexer { input, offset, end }.tokens(None)?);
+        offset += line.len();
+    }
+    Ok(lines)
+}
+
+struct Lexer<'a> {
+    input: &'a str,
+    offset: usize,
+    end: usize,
+}
+
+impl<'a> Lexer<'a> {
+    /// Reads tokens up to the end, or up to the close of the group opened at `open`.
+    fn tokens(&mut self, open: Option<(Delimiter, usize)>) -> Result<Vec<Token<'a>>, Box<Diagnostic>> {
+        let bytes = self.input.as_bytes();
+        let mut tokens = vec![];
+
+        loop {
+            while self.offset < self.end && (bytes[self.offset] == b',' || bytes[self.offset].is_ascii_whitespace()) {
+                self.offset += 1;
+            }
+            if self.offset == self.end {
+                return match open {
+                    None => Ok(tokens),
+                    Some((delimiter, start)) => Err(Box::new(
+                        Diagnostic::at(self.input, start, format!("Unclosed '{}'", delimiter.open()))
+                            .with_hint(format!("expected a '{}' before the end of the line", delimiter.close())),
+                    )),
+                };
+            }
+
+            let b = bytes[self.offset];
+            if let Some(delimiter) = Delimiter::from_open(b) {
+                let start = self.offset;
+                self.offset += 1;
+                let items = self.tokens(Some((delimiter, start)))?;
+                tokens.push(Token::Group { delimiter, items });
+            } else if matches!(b, b']' | b')' | b'}' | b'>') {
+                return match open {
+                    Some((delimiter, _)) if delimiter.close() == b as char => {
+                        self.offset += 1;
+                        Ok(tokens)
+                    },
+                    Some((delimiter, _)) => Err(Box::new(Diagnostic::at(
+                        self.input,
+                        self.offset,
+                        format!("Expected '{}' but found '{}'", delimiter.close(), b as char),
+                    ))),
+                    None => Err(Box::new(Diagnostic::at(self.input, self.offset, format!("Unexpected '{}'", b as char))
+                        .with_hint("there is no group open for it to close"))),
+                };
+            } else {
+                let start = self.offset;
+                while self.offset < self.end && !is_word_boundary(bytes[self.offset]) {
+                    self.offset += 1;
+                }
+                tokens.push(Token::Word(&self.input[start..self.offset]));
+            }
+        }
+    }
+}
+
+fn is_word_boundary(b: u8) -> bool {
+    b == b',' || b.is_ascii_whitespace() || matches!(b, b'[' | b']' | b'(' | b')' | b'{' | b'}' | b'<' | b'>')
+}
+
+
+/// Whether an edge `a -> b` also goes from `b` to `a`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Directed,
+    Undirected,
+}
+
+/// A graph read from adjacency lists, with each node's name interned as a `usize` id.
+///
+/// Ids count up from 0 in the order names first appear, so they can index a `Vec`. A node
+/// which is only ever a target, eg. a final `out`, is still given an id, with no edges out.
+///
+/// # Examples
+///
+/// ```
+/// use advent_utils::parse::{Direction, Graph};
+///
+/// let graph = Graph::parse("you: bbb ccc\nbbb -> out\nccc-out\n", Direction::Directed).unwrap();
+/// let (you, out) = (graph.id("you").unwrap(), graph.id("out").unwrap());
+/// assert_eq!(graph.len(), 4);
+/// assert_eq!(graph.neighbours(you).iter().map(|&id| graph.name(id)).collect::<Vec<_>>(), vec!["bbb", "ccc"]);
+/// assert!(graph.neighbours(out).is_empty());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Graph {
+    direction: Direction,
+    names: Vec<String>,
+    ids: HashMap<String, usize>,
+    edges: Vec<Vec<usize>>,
+}
+
+impl Graph {
+    pub fn new(direction: Direction) -> Self {
+        Graph { direction, names: vec![], ids: HashMap::new(), edges: vec![] }
+    }
+
+    /// Reads one adjacency list per line, in any of these forms:
+    ///
+    /// * `a: b c`, or `a: b, c`, for edges from `a` to `b` and `c`
+    /// * `a -> b, c`, or `a -> b c`, the same
+    /// * `a-b`, for a single edge from `a` to `b`
+    ///
+    /// Blank lines are skipped.
+    pub fn parse(input: &str, direction: Direction) -> Result<Self, Box<Diagnostic>> {
+        let mut graph = Graph::new(direction);
+
+        for (i, line) in input.lines().enumerate().filter(|(_, line)| !line.trim().is_empty()) {
+            let (from, to) = line
+                .split_once("->")
+                .or_else(|| line.split_once(':'))
+                .or_else(|| line.split_once('-'))
+                .ok_or_else(|| {
+                    Diagnostic::whole_line(input, i + 1, "Expected an adjacency list")
+                        .with_hint("lines should look like 'a: b c', 'a -> b, c' or 'a-b'")
+                })?;
+            let from = from.trim();
+            if from.is_empty() {
+                return Err(Box::new(Diagnostic::whole_line(input, i + 1, "Missing the node the edges are from")));
+            }
+
+            let from = graph.intern(from);
+            for to in to.split([',', ' ', '\t']).filter(|to| !to.is_empty()) {
+                let to = graph.intern(to);
+                graph.add_edge(from, to);
+            }
+        }
+        Ok(graph)
+    }
+
+    /// The id for `name`, adding a node with no edges if there isn't one yet.
+    pub fn intern(&mut self, name: &str) -> usize {
+        if let Some(&id) = self.ids.get(name) {
+            return id;
+        }
+        let id = self.names.len();
+        self.names.push(name.to_string());
+        self.ids.insert(name.to_string(), id);
+        self.edges.push(vec![]);
+        id
+    }
+
+    /// Adds an edge from `from` to `to`, and back again if the graph is undirected.
+    pub fn add_edge(&mut self, from: usize, to: usize) {
+        self.edges[from].push(to);
+        if self.direction == Direction::Undirected && from != to {
+            self.edges[to].push(from);
+        }
+    }
+
+    pub fn id(&self, name: &str) -> Option<usize> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn name(&self, id: usize) -> &str {
+        &self.names[id]
+    }
+
+    /// The nodes with an edge from `id`, in the order the edges were read.
+    pub fn neighbours(&self, id: usize) -> &[usize] {
+        &self.edges[id]
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unsigned_iter_empty_str() {
+        let s = "";
+        let vals: Vec<_> = s.as_unsigned_iter::<u32>().collect();
+        assert_eq!(vals, vec![]);
+    }
+
+
+    #[test]
+    fn unsigned_iter_no_vals() {
+        let s = "abc-r";
+        let vals: Vec<_> = s.as_unsigned_iter::<u32>().collect();
+        assert_eq!(vals, vec![]);
+    }
+
+    #[test]
+    fn unsigned_iter_single() {
+        let s = "1";
+        let vals: Vec<_> = s.as_unsigned_iter::<u32>().collect();
+        assert_eq!(vals, vec![1]);
+    }
+
+    #[test]
+    fn unsigned_iter_singl_with_noise() {
+        let s = "b1a";
+        let vals: Vec<_> = s.as_unsigned_iter::<u32>().collect();
+        assert_eq!(vals, vec![1]);
+    }
+
+
+    #[test]
+    fn unsigned_iter_multi() {
+        let s = "b1a34c-7";
+        let vals: Vec<_> = s.as_unsigned_iter::<u32>().collect();
+        assert_eq!(vals, vec![1,34,7]);
+    }
+
+    #[test]
+    fn signed_iter_empty_str() {
+        let s = "";
+        let vals: Vec<_> = s.as_signed_iter::<i32>().collect();
+        assert_eq!(vals, vec![]);
+    }
+
+    #[test]
+    fn signed_iter_no_vals() {
+        let s = "abc-r";
+        let vals: Vec<_> = s.as_signed_iter::<i32>().collect();
+        assert_eq!(vals, vec![]);
+    }
+
+    #[test]
+    fn signed_iter_single() {
+        let s = "-1";
+        let vals: Vec<_> = s.as_signed_iter::<i32>().collect();
+        assert_eq!(vals, vec![-1]);
+    }
+
+    #[test]
+    fn signed_iter_single_with_noise() {
+        let s = "b--1a";
+        let vals: Vec<_> = s.as_signed_iter::<i32>().collect();
+        assert_eq!(vals, vec![-1]);
+    }
+
+    #[test]
+    fn signed_iter_extremes() {
+        let s = "-128,32767,-9223372036854775808";
+        assert_eq!(s.as_signed_iter::<i64>().collect::<Vec<_>>(), vec![-128, 32767, i64::MIN]);
+        assert_eq!("-32768".as_signed_iter::<i16>().next(), Some(i16::MIN));
+    }
+
+    #[test]
+    #[should_panic(expected = "should fit in u8")]
+    fn unsigned_iter_overflow() {
+        "256".as_unsigned_iter::<u8>().next();
+    }
+
+    #[test]
+    fn try_iter_positions() {
+        let s = "a: 12\nb: -3, 4\n";
+        let parsed: Vec<_> = s.try_signed_iter::<i32>().collect::<Result<_, _>>().unwrap();
+        assert_eq!(parsed, vec![
+            Parsed { value: 12, offset: 3, line: 1, column: 4 },
+            Parsed { value: -3, offset: 9, line: 2, column: 4 },
+            Parsed { value: 4, offset: 13, line: 2, column: 8 },
+        ]);
+    }
+
+    #[test]
+    fn try_iter_overflow_continues() {
+        let s = "300 7";
+        let mut it = s.try_unsigned_iter::<u8>();
+        assert_eq!(it.next(), Some(Err(ParseError::Overflow { offset: 0, line: 1, column: 1, type_name: "u8" })));
+        assert_eq!(it.next().unwrap().unwrap().value, 7);
+    }
+
+    #[test]
+    fn exactly_errors() {
+        assert_eq!("a".try_unsigned_iter::<u8>().exactly::<1>(), Err(ParseError::WrongCount { expected: 1, found: 0 }));
+        assert_eq!("1 2 3".try_unsigned_iter::<u8>().exactly::<3>(), Ok([1, 2, 3]));
+        assert_eq!(ParseError::WrongCount { expected: 2, found: 1 }.to_string(), "expected exactly 2 integers, found 1");
+    }
+
+    #[test]
+    fn radix_iter_prefixes() {
+        assert_eq!("0xff, 0X1A, 10".as_radix_iter::<u32>(16).collect::<Vec<_>>(), vec![255, 26, 16]);
+        assert_eq!("0b101 11 -0b1".as_radix_iter::<i8>(2).collect::<Vec<_>>(), vec![5, 3, -1]);
+        assert_eq!("0b1".as_radix_iter::<u32>(16).collect::<Vec<_>>(), vec![0xb1]);
+        assert_eq!("0x".as_radix_iter::<u32>(16).collect::<Vec<_>>(), vec![0]);
+        assert_eq!("777 8".as_radix_iter::<u16>(8).collect::<Vec<_>>(), vec![511]);
+    }
+
+    #[test]
+    fn all_widths() {
+        assert_eq!("-128 127".as_signed_iter::<i8>().collect::<Vec<_>>(), vec![i8::MIN, i8::MAX]);
+        assert_eq!("-1".as_signed_iter::<isize>().next(), Some(-1));
+        assert_eq!("340282366920938463463374607431768211455".as_unsigned_iter::<u128>().next(), Some(u128::MAX));
+    }
+
+    #[test]
+    fn float_iter_forms() {
+        let values: Vec<f64> = "1e3 2.5e+1 -4.0e-1 7e a.5 1.2.3".as_float_iter().collect();
+        assert_eq!(values, vec![1000.0, 25.0, -0.4, 7.0, 5.0, 1.2, 3.0]);
+    }
+
+    #[test]
+    fn float_iter_ranges() {
+        assert_eq!("1.5-2.5".as_float_iter().collect::<Vec<_>>(), vec![1.5, 2.5]);
+        assert_eq!("5-3 x-2 -1--4".as_float_iter().collect::<Vec<_>>(), vec![5.0, 3.0, -2.0, -1.0, -4.0]);
+    }
+
+    #[test]
+    fn digit_iter() {
+        assert_eq!("a1b23".as_digit_iter::<u64>().collect::<Vec<_>>(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn sections_skip_extra_blank_lines() {
+        let input = "\n\na\nb\n\n\n \t\nc\n\n";
+        let sections: Vec<_> = input.sections().collect();
+        assert_eq!(sections, vec![Section { index: 0, line: 3, text: "a\nb" }, Section { index: 1, line: 8, text: "c" }]);
+        assert_eq!("".sections().count(), 0);
+        assert_eq!("x".sections().next(), Some(Section { index: 0, line: 1, text: "x" }));
+    }
+
+    #[test]
+    fn sections_wrong_count() {
+        let err = "a\n\nb\n\nc".sections().exactly::<2>().unwrap_err();
+        assert_eq!(err, SectionError::WrongCount { expected: 2, found: 3 });
+    }
+
+    #[test]
+    fn section_parse_error_has_index() {
+        let section = "1\n\nx".sections().nth(1).unwrap();
+        let err = section.parse(|text| text.parse::<u32>()).unwrap_err();
+        assert_eq!(err.to_string(), "section 2: invalid digit found in string");
+    }
+
+    #[derive(Debug, PartialEq, crate::pattern::AocParse)]
+    #[aoc(sep = ",")]
+    struct Junction {
+        x: i64,
+        y: i64,
+    }
+
+    #[test]
+    fn parse_records_points_at_bad_line() {
+        let junctions = "1,2\n\n-3,4\n".parse_records::<Junction>().unwrap();
+        assert_eq!(junctions, vec![Junction { x: 1, y: 2 }, Junction { x: -3, y: 4 }]);
+
+        let diagnostic = "1,2\n3;4\n".parse_records::<Junction>().unwrap_err();
+        assert_eq!((diagnostic.line, diagnostic.width, diagnostic.source_line.as_str()), (2, 3, "3;4"));
+        assert_eq!(diagnostic.hint.as_deref(), Some(r#"expected a line matching "{},{}""#));
+
+        let section = "a\nb\n\n1,2\n3;4\n".sections().nth(1).unwrap();
+        let diagnostic = section.parse_records::<Junction>().unwrap_err();
+        assert_eq!((diagnostic.line, diagnostic.source_line.as_str()), (5, "3;4"));
+    }
+
+    #[test]
+    fn tokenize_nested_groups() {
+        let tokens = tokenize("<a, (b c)> {}").unwrap();
+        let word = Token::Word;
+        let inner = Token::Group { delimiter: Delimiter::Paren, items: vec![word("b"), word("c")] };
+        assert_eq!(tokens, vec![
+            Token::Group { delimiter: Delimiter::Angle, items: vec![word("a"), inner] },
+            Token::Group { delimiter: Delimiter::Brace, items: vec![] },
+        ]);
+        assert_eq!(tokens[0].to_string(), "<a,(b,c)>");
+    }
+
+    #[test]
+    fn tokenize_lines_errors_point_at_line() {
+        let lines = tokenize_lines("[a]\r\n\n(b)\n").unwrap();
+        assert_eq!(lines.iter().map(Vec::len).collect::<Vec<_>>(), vec![1, 0, 1]);
+
+        let err = tokenize_lines("(a)\nb (c\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.message.as_str()), (2, 3, "Unclosed '('"));
+        let err = tokenize_lines("a]").unwrap_err();
+        assert_eq!((err.line, err.column, err.message.as_str()), (1, 2, "Unexpected ']'"));
+    }
+
+    #[test]
+    fn graph_undirected_edges() {
+        let graph = Graph::parse("a-b\n\nb-c\nc-c\n", Direction::Undirected).unwrap();
+        let b = graph.id("b").unwrap();
+        assert_eq!(graph.neighbours(b), &[0, 2]);
+        assert_eq!(graph.neighbours(graph.id("c").unwrap()), &[b, 2]);
+        assert_eq!(graph.id("d"), None);
+    }
+
+    #[test]
+    fn graph_intern_sink() {
+        let mut graph = Graph::parse("a: b,  c\n", Direction::Directed).unwrap();
+        assert_eq!(graph.neighbours(0), &[1, 2]);
+        let out = graph.intern("out");
+        assert_eq!((out, graph.intern("out"), graph.len()), (3, 3, 4));
+        assert_eq!(graph.name(out), "out");
+    }
+
+    #[test]
+    fn graph_parse_errors() {
+        let err = Graph::parse("a: b\nc d\n", Direction::Directed).unwrap_err();
+        assert_eq!((err.line, err.width, err.message.as_str()), (2, 3, "Expected an adjacency list"));
+        let err = Graph::parse(" -> b\n", Direction::Directed).unwrap_err();
+        assert_eq!(err.message, "Missing the node the edges are from");
+    }
+
+    #[test]
+    fn diagnostic_position() {
+        let input = "ab\r\ncdé\nfg";
+        let diagnostic = Diagnostic::at(input, 9, "bad");
+        assert_eq!((diagnostic.line, diagnostic.column, diagnostic.source_line.as_str()), (3, 1, "fg"));
+        let diagnostic = Diagnostic::at(input, 5, "bad").with_width(2);
+        assert_eq!((diagnostic.line, diagnostic.column, diagnostic.source_line.as_str()), (2, 2, "cdé"));
+        assert_eq!(diagnostic.to_string(), "error: bad\n --> line 2, column 2\n  |\n2 | cdé\n  |  ^^");
+    }
+
+    #[test]
+    fn signed_iter_multi() {
+        let s = "-1,-3,-4,g6,h23-";
+        let vals: Vec<_> = s.as_signed_iter::<i32>().collect();
+        assert_eq!(vals, vec![-1,-3,-4,6,23]);
+    }
+}
\ No newline at end of file
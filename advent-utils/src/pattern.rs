@@ -0,0 +1,318 @@
+//!
+//! Scanf-style parsing of lines against a pattern such as `"{}: {}"`, via `parse_pattern!`.
+//!
+//! Each `{}` in the pattern captures the text up to the next literal part of the pattern, or
+//! to the end of the line. `{{` and `}}` match literal braces. A field parsed as a `Vec` is
+//! split on whitespace, or on the separator given inside the braces, eg. `{,}`.
+//!
+//! `#[derive(AocParse)]` builds the same kind of pattern from a struct's fields, or from an
+//! enum's variants, so a line can be parsed straight into a named type.
+//!
+
+use std::error::Error;
+use std::fmt;
+
+/// A field captured from a line, before being converted to its type.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Field<'a> {
+    pub text: &'a str,
+    pub separator: Option<&'a str>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PatternError {
+    pub line: String,
+    pub pattern: &'static str,
+    pub message: String,
+}
+
+impl fmt::Display for PatternError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {:?} does not match pattern {:?}: {}", self.line, self.pattern, self.message)
+    }
+}
+
+impl Error for PatternError {}
+
+/// A type parsed from a single line of input, usually with `#[derive(AocParse)]`.
+///
+/// The derive also implements `FromStr`, and `FromField` so that a derived type can be a
+/// field of another, eg. `Vec<Point>` separated by `{ -> }`. `AocParseExt::parse_records`
+/// parses every line of an input.
+///
+/// A struct is parsed from its fields joined by a space, or by `#[aoc(sep = ",")]`. Or
+/// `#[aoc(format = "...")]` gives the whole line, naming the fields in braces, which can be
+/// in any order. A `Vec` field is split on whitespace, or on its own `#[aoc(sep = ",")]`.
+///
+/// An enum picks its variant by the token the line starts with, the variant's name unless
+/// it has `#[aoc(token = "...")]`. The token must be followed by whitespace or the end of
+/// the line. The rest of the line, after the whitespace, is parsed into the variant's fields
+/// in the same way as a struct. Longer tokens are tried first, falling back to shorter ones
+/// when the fields don't match.
+///
+/// ```
+/// use advent_utils::pattern::AocParse;
+///
+/// #[derive(Debug, PartialEq, AocParse)]
+/// #[aoc(format = "{width}x{height}: {counts}")]
+/// struct Region {
+///     width: u32,
+///     height: u32,
+///     counts: Vec<u32>,
+/// }
+///
+/// #[derive(Debug, PartialEq, AocParse)]
+/// enum Turn {
+///     #[aoc(token = "L")]
+///     Left(i32),
+///     #[aoc(token = "R")]
+///     Right(i32),
+///     #[aoc(token = "noop")]
+///     Noop,
+/// }
+///
+/// let region: Region = "12x5: 1 0 2".parse().unwrap();
+/// assert_eq!(region, Region { width: 12, height: 5, counts: vec![1, 0, 2] });
+/// assert_eq!(Turn::parse_line("R 14"), Ok(Turn::Right(14)));
+/// assert_eq!(Turn::parse_line("noop"), Ok(Turn::Noop));
+/// assert_eq!(Turn::parse_line("U3").unwrap_err().message, "no variant's token starts the line");
+/// ```
+pub trait AocParse: Sized {
+    fn parse_line(line: &str) -> Result<Self, PatternError>;
+}
+
+pub use advent_derive::AocParse;
+
+/// A type which a pattern field can be parsed as.
+pub trait FromField: Sized {
+    fn from_field(field: Field) -> Result<Self, String>;
+}
+
+impl FromField for String {
+    fn from_field(field: Field) -> Result<Self, String> {
+        Ok(field.text.to_string())
+    }
+}
+
+macro_rules! impl_from_field {
+    ($($t:ty)*) => ($(
+        impl FromField for $t {
+            fn from_field(field: Field) -> Result<Self, String> {
+                field.text.trim().parse().map_err(|err| format!("{:?} is not a valid {}: {}", field.text, stringify!($t), err))
+            }
+        }
+    )*)
+}
+
+impl_from_field!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize f32 f64 char bool);
+
+impl<T: FromField> FromField for Vec<T> {
+    fn from_field(field: Field) -> Result<Self, String> {
+        let items: Box<dyn Iterator<Item = &str>> = match field.separator {
+            Some(separator) => Box::new(field.text.split(separator).map(str::trim)),
+            None => Box::new(field.text.split_whitespace()),
+        };
+        items
+            .filter(|item| !item.is_empty())
+            .map(|item| T::from_field(Field { text: item, separator: None }))
+            .collect()
+    }
+}
+
+enum Part<'a> {
+    Literal(String),
+    Field(Option<&'a str>),
+}
+
+fn compile(pattern: &str) -> Vec<Part<'_>> {
+    let mut parts = vec![];
+    let mut literal = String::new();
+    let mut rest = pattern;
+
+    while let Some(c) = rest.chars().next() {
+        if rest.starts_with("{{") || rest.starts_with("}}") {
+            literal.push(c);
+            rest = &rest[2..];
+        } else if c == '{' {
+            let end = rest.find('}').unwrap_or_else(|| panic!("pattern {:?} has an unclosed field", pattern));
+            if !literal.is_empty() {
+                parts.push(Part::Literal(std::mem::take(&mut literal)));
+            }
+            let separator = &rest[1..end];
+            parts.push(Part::Field((!separator.is_empty()).then_some(separator)));
+            rest = &rest[end + 1..];
+        } else {
+            literal.push(c);
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+    if !literal.is_empty() {
+        parts.push(Part::Literal(literal));
+    }
+    parts
+}
+
+/// Splits `line` into the fields of `pattern`. Used by `parse_pattern!`.
+pub fn match_pattern<'a>(pattern: &'static str, line: &'a str) -> Result<Vec<Field<'a>>, PatternError> {
+    let error = |message: String| PatternError { line: line.to_string(), pattern, message };
+    let parts = compile(pattern);
+    let mut fields = vec![];
+    let mut rest = line;
+
+    for (i, part) in parts.iter().enumerate() {
+        match part {
+            Part::Literal(literal) => {
+                rest = rest.strip_prefix(literal.as_str()).ok_or_else(|| match fields.len() {
+                    0 => error(format!("expected {:?} at the start", literal)),
+                    n => error(format!("expected {:?} after field {}, found {:?}", literal, n, rest)),
+                })?;
+            },
+            Part::Field(separator) => {
+                // A field runs up to the next literal, which always follows it if anything does.
+                let end = match parts.get(i + 1) {
+                    Some(Part::Literal(literal)) => rest.find(literal.as_str()).ok_or_else(|| {
+                        error(format!("expected {:?} after field {}", literal, fields.len() + 1))
+                    })?,
+                    Some(Part::Field(_)) => panic!("pattern {:?} has two fields with nothing between them", pattern),
+                    None => rest.len(),
+                };
+                fields.push(Field { text: &rest[..end], separator: *separator });
+                rest = &rest[end..];
+            },
+        }
+    }
+
+    if !rest.is_empty() {
+        return Err(error(format!("unexpected {:?} at the end", rest)));
+    }
+    Ok(fields)
+}
+
+/// Converts one field, quoting the line and the field's position if it can't be parsed.
+pub fn parse_field<T: FromField>(pattern: &'static str, line: &str, index: usize, field: Field) -> Result<T, PatternError> {
+    T::from_field(field).map_err(|message| PatternError {
+        line: line.to_string(),
+        pattern,
+        message: format!("field {} {}", index + 1, message),
+    })
+}
+
+/// Parses a line against a pattern, returning a tuple of the typed fields.
+///
+/// ```
+/// use advent_utils::parse_pattern;
+///
+/// let (name, outputs) = parse_pattern!("{}: {}", "aaa: bbb ccc" => String, Vec<String>).unwrap();
+/// assert_eq!((name.as_str(), outputs), ("aaa", vec!["bbb".to_string(), "ccc".to_string()]));
+///
+/// let (lights, joltages) = parse_pattern!("[{}] {{{,}}}", "[.##.] {3,5,4}" => String, Vec<u32>).unwrap();
+/// assert_eq!((lights.as_str(), joltages), (".##.", vec![3, 5, 4]));
+///
+/// let err = parse_pattern!("{},{}", "12,x" => i64, i64).unwrap_err();
+/// assert_eq!(err.to_string(), r#"line "12,x" does not match pattern "{},{}": field 2 "x" is not a valid i64: invalid digit found in string"#);
+/// ```
+#[macro_export]
+macro_rules! parse_pattern {
+    ($pattern:literal, $line:expr => $($ty:ty),+ $(,)?) => {{
+        let line: &str = $line;
+        $crate::pattern::match_pattern($pattern, line).and_then(|fields| {
+            let expected = [$(stringify!($ty)),+].len();
+            assert_eq!(fields.len(), expected, "pattern {:?} should have one field per type", $pattern);
+            let mut fields = fields.into_iter().enumerate();
+            Ok(($({
+                let (index, field) = fields.next().unwrap();
+                $crate::pattern::parse_field::<$ty>($pattern, line, index, field)?
+            },)+))
+        })
+    }};
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn match_literals_and_fields() {
+        let fields = match_pattern("{},{}", "97579,50266").unwrap();
+        assert_eq!(fields.iter().map(|f| f.text).collect::<Vec<_>>(), vec!["97579", "50266"]);
+    }
+
+    #[test]
+    fn separator_and_escaped_braces() {
+        let fields = match_pattern("[{}] {} {{{,}}}", "[.#] (0) (1,2) {3,4}").unwrap();
+        assert_eq!(fields[1], Field { text: "(0) (1,2)", separator: None });
+        assert_eq!(fields[2], Field { text: "3,4", separator: Some(",") });
+    }
+
+    #[test]
+    fn mismatch_errors() {
+        let err = match_pattern("{}: {}", "abc").unwrap_err();
+        assert_eq!(err.message, r#"expected ": " after field 1"#);
+        let err = match_pattern("<{}>", "<1>!").unwrap_err();
+        assert_eq!(err.to_string(), r#"line "<1>!" does not match pattern "<{}>": unexpected "!" at the end"#);
+        let err = match_pattern("({})", "1)").unwrap_err();
+        assert_eq!(err.message, r#"expected "(" at the start"#);
+    }
+
+    #[derive(Debug, PartialEq, AocParse)]
+    #[aoc(sep = ",")]
+    struct Point(i64, i64);
+
+    #[derive(Debug, PartialEq, AocParse)]
+    #[aoc(format = "{name} -> {path}")]
+    struct Route {
+        name: String,
+        #[aoc(sep = " -> ")]
+        path: Vec<Point>,
+    }
+
+    #[derive(Debug, PartialEq, AocParse)]
+    enum Command {
+        #[aoc(token = "turn on", format = "{0} through {1}")]
+        TurnOn(Point, Point),
+        #[aoc(token = "turn")]
+        Turn { degrees: i32 },
+        #[aoc(token = "turn")]
+        TurnBy(String, i32),
+        #[aoc(token = "L")]
+        Left(i32),
+    }
+
+    #[test]
+    fn derive_nested_records() {
+        let route: Route = "a -> 1,2 -> 3,-4".parse().unwrap();
+        assert_eq!(route, Route { name: "a".to_string(), path: vec![Point(1, 2), Point(3, -4)] });
+        let err = Route::parse_line("a -> 1,2 -> 3").unwrap_err();
+        assert_eq!(err.message, r#"field 2 line "3" does not match pattern "{},{}": expected "," after field 1"#);
+    }
+
+    #[test]
+    fn derive_enum_longest_token_first() {
+        assert_eq!(Command::parse_line("turn on 0,0 through 2,2"), Ok(Command::TurnOn(Point(0, 0), Point(2, 2))));
+        assert_eq!(Command::parse_line("turn 90"), Ok(Command::Turn { degrees: 90 }));
+        let err = Command::parse_line("turn left").unwrap_err();
+        assert_eq!((err.line.as_str(), err.pattern), ("turn left", "{}"));
+    }
+
+    #[test]
+    fn derive_enum_token_is_whole_word() {
+        assert_eq!(Command::parse_line("L 3"), Ok(Command::Left(3)));
+        assert_eq!(Command::parse_line("Left 3").unwrap_err().message, "no variant's token starts the line");
+        assert_eq!(Command::parse_line("turnip 5").unwrap_err().message, "no variant's token starts the line");
+    }
+
+    #[test]
+    fn derive_enum_falls_back_to_shorter_token() {
+        assert_eq!(Command::parse_line("turn on 90"), Ok(Command::TurnBy("on".to_string(), 90)));
+        assert_eq!(Command::parse_line("turn left 90"), Ok(Command::TurnBy("left".to_string(), 90)));
+        let err = Command::parse_line("turn on 1,1 through x").unwrap_err();
+        assert_eq!(err.pattern, "{} through {}");
+    }
+
+    #[test]
+    fn repeated_field_errors_quote_item() {
+        let err = parse_pattern!("{}", "1 2 z" => Vec<u8>).unwrap_err();
+        assert_eq!(err.message, r#"field 1 "z" is not a valid u8: invalid digit found in string"#);
+    }
+}
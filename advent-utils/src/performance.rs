@@ -0,0 +1,54 @@
+use std::time::{Duration, Instant};
+
+#[macro_export]
+macro_rules! time_execution {
+    ($label: expr, $expression:expr) => {{
+        let start = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).expect("System time before Unix epoch");
+        let result = $expression;
+        let end = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).expect("System time before Unix epoch");
+        let total_time = end - start;
+        $crate::log!($crate::log::Level::Normal, "{} executed in {:?}", $label, total_time);
+        result
+    }};
+}
+
+/// Runs `f` and returns its result along with how long it took, without printing anything.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
+
+/// Formats a duration with 3 significant figures in the most readable unit, eg. `1.23 ms`.
+///
+/// ```
+/// use std::time::Duration;
+/// use advent_utils::performance::format_duration;
+///
+/// assert_eq!(format_duration(Duration::from_micros(1_234)), "1.23 ms");
+/// ```
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos() as f64;
+    let (value, unit) = match nanos {
+        n if n < 1e3 => (n, "ns"),
+        n if n < 1e6 => (n / 1e3, "µs"),
+        n if n < 1e9 => (n / 1e6, "ms"),
+        n => (n / 1e9, "s"),
+    };
+    let decimals = if value < 10.0 { 2 } else if value < 100.0 { 1 } else { 0 };
+    format!("{:.*} {}", decimals, value, unit)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_duration_units() {
+        assert_eq!(format_duration(Duration::from_nanos(468)), "468 ns");
+        assert_eq!(format_duration(Duration::from_nanos(1_234)), "1.23 µs");
+        assert_eq!(format_duration(Duration::from_micros(66_407)), "66.4 ms");
+        assert_eq!(format_duration(Duration::from_millis(2_500)), "2.50 s");
+    }
+}
@@ -0,0 +1,257 @@
+use std::fmt;
+use std::marker::PhantomData;
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::{log, time_execution};
+use crate::log::{self as logging, Level};
+use crate::parse::Diagnostic;
+use crate::performance::measure;
+
+/// Defines a solver from its preprocess and part functions.
+///
+/// Flags can be added at the end:
+///
+/// * `line_order_independent` marks inputs whose lines can be given in any order without
+///   changing the answers, so the determinism check can shuffle them.
+/// * `fallible` marks a preprocess function returning `Result<T, Box<Diagnostic>>`, for inputs
+///   which can be rejected with an error pointing at the problem rather than a panic.
+///
+/// ```
+/// use advent_utils::define_solver;
+/// use advent_utils::solver::SolverDyn;
+///
+/// define_solver!(SumSolver, "2025", "00", Vec<u32>, preprocess, part_one, part_two);
+///
+/// fn preprocess(input: &str) -> Vec<u32> {
+///     input.lines().map(|line| line.parse().unwrap()).collect()
+/// }
+///
+/// fn part_one(values: &Vec<u32>) -> String {
+///     values.iter().sum::<u32>().to_string()
+/// }
+///
+/// fn part_two(values: &Vec<u32>) -> String {
+///     values.iter().max().unwrap().to_string()
+/// }
+///
+/// let solution = SumSolver.run("3\n4\n").unwrap();
+/// assert_eq!((solution.one.as_str(), solution.two.as_str()), ("7", "4"));
+/// assert_eq!(SumSolver.session("5\n").unwrap().part_one(), "5");
+/// ```
+///
+/// ```
+/// use advent_utils::define_solver;
+/// use advent_utils::parse::Diagnostic;
+/// use advent_utils::solver::SolverDyn;
+///
+/// define_solver!(DigitSolver, "2025", "00", Vec<u32>, preprocess, part_one, part_one, fallible);
+///
+/// fn preprocess(input: &str) -> Result<Vec<u32>, Box<Diagnostic>> {
+///     input
+///         .char_indices()
+///         .map(|(i, c)| c.to_digit(10).ok_or_else(|| Diagnostic::at(input, i, "Expected a digit").into()))
+///         .collect()
+/// }
+///
+/// fn part_one(digits: &Vec<u32>) -> String {
+///     digits.iter().sum::<u32>().to_string()
+/// }
+///
+/// let diagnostic = DigitSolver.run("12x").unwrap_err();
+/// assert_eq!((diagnostic.day.as_deref(), diagnostic.column), (Some("00"), 3));
+/// ```
+#[macro_export]
+macro_rules! define_solver {
+    (
+        $struct_name:ident,
+        $year:expr,
+        $day:expr,
+        $input_type:ty,
+        $preprocess_fn:expr,
+        $solve_one_fn:expr,
+        $solve_two_fn:expr
+        $(, $flag:ident)* $(,)?
+    ) => {
+        pub struct $struct_name;
+
+        impl $crate::solver::Solver<$input_type> for $struct_name {
+            fn preprocess(input: &str) -> Result<$input_type, Box<$crate::parse::Diagnostic>> {
+                $crate::__solver_preprocess!($preprocess_fn, input, $($flag)*)
+            }
+            fn solve_one(input: &$input_type) -> String {
+                $solve_one_fn(input)
+            }
+            fn solve_two(input: &$input_type) -> String {
+                $solve_two_fn(input)
+            }
+        }
+
+        impl $crate::solver::SolverDyn for $struct_name {
+            fn year(&self) -> &str { $year }
+            fn day(&self) -> &str { $day }
+            fn line_order_independent(&self) -> bool { $crate::__solver_line_order_independent!($($flag)*) }
+            fn solve(&self, input: &str) -> (String, String) {
+                $crate::solver::Solver::solve(self, input)
+            }
+            fn run(&self, input: &str) -> Result<$crate::solver::Solution, Box<$crate::parse::Diagnostic>> {
+                $crate::solver::Solver::run(self, input)
+            }
+            fn session(&self, input: &str) -> Result<Box<dyn $crate::solver::Session>, Box<$crate::parse::Diagnostic>> {
+                let session = $crate::solver::Preprocessed::<$struct_name, $input_type>::new(self, input)?;
+                Ok(Box::new(session))
+            }
+        }
+    };
+}
+
+/// Calls a solver's preprocess function, wrapping its result unless it is `fallible`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __solver_preprocess {
+    ($preprocess_fn:expr, $input:expr, ) => { Ok($preprocess_fn($input)) };
+    ($preprocess_fn:expr, $input:expr, fallible $($rest:ident)*) => { $preprocess_fn($input) };
+    ($preprocess_fn:expr, $input:expr, $other:ident $($rest:ident)*) => {
+        $crate::__solver_preprocess!($preprocess_fn, $input, $($rest)*)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __solver_line_order_independent {
+    () => { false };
+    (line_order_independent $($rest:ident)*) => { true };
+    ($other:ident $($rest:ident)*) => { $crate::__solver_line_order_independent!($($rest)*) };
+}
+
+pub trait Solver<T>: SolverDyn {
+
+    fn solve_one(input: &T) -> String;
+
+    fn solve_two(input: &T) -> String;
+
+    /// Put any work which should be executed for both part 1 and part 2 here.
+    /// eg. parsing logic.
+    fn preprocess(input: &str) -> Result<T, Box<Diagnostic>>;
+
+    /// Preprocesses the input, recording which puzzle it was for on any diagnostic.
+    fn preprocess_for_day(&self, input: &str) -> Result<T, Box<Diagnostic>> {
+        Self::preprocess(input).map_err(|diagnostic| Box::new(diagnostic.for_day(self.year(), self.day())))
+    }
+
+    /// Solves and prints both parts. An input rejected by preprocessing panics with the diagnostic.
+    fn solve<'a>(&self, input:  &'a str) -> (String, String) {
+        log!(Level::Normal, "\n- - - - - {} day {} - - - - -", self.year(), self.day());
+        let data = time_execution!(format!("Preprocessing"), self.preprocess_for_day(input))
+            .unwrap_or_else(|diagnostic| panic!("{}", diagnostic));
+        let one = time_execution!(format!("Part 1"), Self::solve_one(&data));
+        logging::answer(&one);
+        let two = time_execution!(format!("Part 2"), Self::solve_two(&data));
+        logging::answer(&two);
+        log!(Level::Normal, "- - - - - - - - - - - - - - - -");
+        (one, two)
+    }
+
+    /// Solves both parts without printing anything, timing each step.
+    fn run(&self, input: &str) -> Result<Solution, Box<Diagnostic>> {
+        let (data, preprocess_time) = measure(|| self.preprocess_for_day(input));
+        let data = data?;
+        let (one, part_one_time) = measure(|| Self::solve_one(&data));
+        let (two, part_two_time) = measure(|| Self::solve_two(&data));
+        Ok(Solution { one, two, preprocess_time, part_one_time, part_two_time })
+    }
+}
+
+pub trait SolverDyn: Send + Sync {
+    fn year(&self) -> &str;
+    fn day(&self) -> &str;
+    /// Whether the input's lines can be reordered without changing the answers.
+    fn line_order_independent(&self) -> bool { false }
+    fn solve(&self, input: &str) -> (String, String);
+    fn run(&self, input: &str) -> Result<Solution, Box<Diagnostic>>;
+    /// Preprocesses the input once, so the parts can be run against it repeatedly.
+    fn session(&self, input: &str) -> Result<Box<dyn Session>, Box<Diagnostic>>;
+}
+
+/// A preprocessed input which the parts can be run against, used by the REPL.
+pub trait Session {
+    fn part_one(&self) -> String;
+    fn part_two(&self) -> String;
+    /// The preprocessed value, pretty printed with `Debug`.
+    fn inspect(&self) -> String;
+}
+
+pub struct Preprocessed<S, T> {
+    data: T,
+    solver: PhantomData<S>,
+}
+
+impl<S: Solver<T>, T> Preprocessed<S, T> {
+    pub fn new(solver: &S, input: &str) -> Result<Self, Box<Diagnostic>> {
+        Ok(Preprocessed { data: solver.preprocess_for_day(input)?, solver: PhantomData })
+    }
+}
+
+impl<S: Solver<T>, T: fmt::Debug> Session for Preprocessed<S, T> {
+    fn part_one(&self) -> String {
+        S::solve_one(&self.data)
+    }
+
+    fn part_two(&self) -> String {
+        S::solve_two(&self.data)
+    }
+
+    fn inspect(&self) -> String {
+        format!("{:#?}", self.data)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Solution {
+    pub one: String,
+    pub two: String,
+    pub preprocess_time: Duration,
+    pub part_one_time: Duration,
+    pub part_two_time: Duration,
+}
+
+impl Solution {
+    pub fn get(&self, part: Part) -> &str {
+        match part {
+            Part::One => &self.one,
+            Part::Two => &self.two,
+        }
+    }
+
+    pub fn total_time(&self) -> Duration {
+        self.preprocess_time + self.part_one_time + self.part_two_time
+    }
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            part => Err(format!("Part should be 1 or 2, got {}", part)),
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
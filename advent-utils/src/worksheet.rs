@@ -0,0 +1,121 @@
+//!
+//! Parsing for column-aligned text, where what matters is which column a character is in
+//! rather than how the line splits on whitespace.
+//!
+
+/// A grid of characters with every line padded with spaces to the same width.
+///
+/// # Examples
+///
+/// ```
+/// use advent_utils::worksheet::Worksheet;
+///
+/// let sheet = Worksheet::new("123 45\n 6  7\n*   +");
+/// let blocks = sheet.blocks();
+/// assert_eq!(blocks[0].rows, vec!["123", " 6 ", "*  "]);
+/// assert_eq!(blocks[0].columns, vec!["1 *", "26 ", "3  "]);
+/// assert_eq!(blocks[1].start, 4);
+/// assert_eq!(blocks[1].rows, vec!["45", "7 ", "+ "]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Worksheet {
+    cells: Vec<Vec<char>>,
+    width: usize,
+}
+
+/// A run of columns with no all-blank column inside it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Block {
+    /// The index of the block's first column.
+    pub start: usize,
+    /// The block's part of each line, top to bottom.
+    pub rows: Vec<String>,
+    /// Each of the block's columns read top to bottom, left to right.
+    pub columns: Vec<String>,
+}
+
+impl Worksheet {
+    pub fn new(input: &str) -> Self {
+        let mut cells: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
+        let width = cells.iter().map(Vec::len).max().unwrap_or(0);
+        for row in &mut cells {
+            row.resize(width, ' ');
+        }
+        Worksheet { cells, width }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Reads column `i` from top to bottom.
+    pub fn column(&self, i: usize) -> String {
+        self.cells.iter().map(|row| row[i]).collect()
+    }
+
+    /// Every column, left to right, which is the worksheet transposed.
+    pub fn columns(&self) -> Vec<String> {
+        (0..self.width).map(|i| self.column(i)).collect()
+    }
+
+    fn is_blank_column(&self, i: usize) -> bool {
+        self.cells.iter().all(|row| row[i].is_whitespace())
+    }
+
+    /// Splits the worksheet into blocks at columns which are blank in every line.
+    pub fn blocks(&self) -> Vec<Block> {
+        let mut blocks = vec![];
+        let mut start = None;
+
+        for i in 0..=self.width {
+            match (start, i == self.width || self.is_blank_column(i)) {
+                (None, false) => start = Some(i),
+                (Some(first), true) => {
+                    blocks.push(self.block(first, i));
+                    start = None;
+                },
+                _ => {},
+            }
+        }
+        blocks
+    }
+
+    fn block(&self, start: usize, end: usize) -> Block {
+        Block {
+            start,
+            rows: self.cells.iter().map(|row| row[start..end].iter().collect()).collect(),
+            columns: (start..end).map(|i| self.column(i)).collect(),
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pads_ragged_lines() {
+        let sheet = Worksheet::new("ab\na\nabc");
+        assert_eq!((sheet.width(), sheet.height()), (3, 3));
+        assert_eq!(sheet.columns(), vec!["aaa", "b b", "  c"]);
+    }
+
+    #[test]
+    fn blocks_split_on_blank_columns() {
+        let sheet = Worksheet::new("1  2\n1  22");
+        let blocks = sheet.blocks();
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(blocks[0], Block { start: 0, rows: vec!["1".into(), "1".into()], columns: vec!["11".into()] });
+        assert_eq!(blocks[1].columns, vec!["22", " 2"]);
+    }
+
+    #[test]
+    fn empty_worksheet() {
+        assert!(Worksheet::new("").blocks().is_empty());
+    }
+}
@@ -0,0 +1,86 @@
+//!
+//! Writes a table of the solvers whose source contains tests, for `status` to include, so
+//! it doesn't need the sources at runtime.
+//!
+//! With the `embedded-inputs` feature, also writes a table of every `inputs/yyyy/dd.txt` for
+//! `utils::inputs` to include, so the binary carries its inputs with it.
+//!
+
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+fn main() {
+    write_solver_tests();
+    if env::var_os("CARGO_FEATURE_EMBEDDED_INPUTS").is_some() {
+        write_embedded_inputs();
+    }
+}
+
+/// Lists the year and day of every `src/year_yyyy/day_dd.rs` containing a `#[test]`.
+fn write_solver_tests() {
+    let src_dir = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("src");
+    println!("cargo:rerun-if-changed={}", src_dir.display());
+
+    let mut days = vec![];
+    for year in fs::read_dir(&src_dir).unwrap() {
+        let year = year.unwrap();
+        let Some(year_name) = year.file_name().to_string_lossy().strip_prefix("year_").map(String::from) else {
+            continue;
+        };
+        println!("cargo:rerun-if-changed={}", year.path().display());
+        for day in fs::read_dir(year.path()).unwrap() {
+            let path = day.unwrap().path();
+            println!("cargo:rerun-if-changed={}", path.display());
+            let Some(day_name) = path.file_stem().unwrap().to_string_lossy().strip_prefix("day_").map(String::from) else {
+                continue;
+            };
+            if fs::read_to_string(&path).unwrap().contains("#[test]") {
+                days.push((year_name.clone(), day_name));
+            }
+        }
+    }
+    days.sort();
+
+    let mut table = String::from("pub static SOLVER_TESTS: &[(&str, &str)] = &[\n");
+    for (year, day) in days {
+        writeln!(table, "    ({:?}, {:?}),", year, day).unwrap();
+    }
+    table.push_str("];\n");
+
+    let out_path = Path::new(&env::var("OUT_DIR").unwrap()).join("solver_tests.rs");
+    fs::write(out_path, table).unwrap();
+}
+
+fn write_embedded_inputs() {
+    let inputs_dir = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("inputs");
+    println!("cargo:rerun-if-changed={}", inputs_dir.display());
+
+    let mut inputs = vec![];
+    for year in fs::read_dir(&inputs_dir).expect("inputs directory should exist") {
+        let year = year.unwrap();
+        if !year.file_type().unwrap().is_dir() {
+            continue;
+        }
+        println!("cargo:rerun-if-changed={}", year.path().display());
+        for day in fs::read_dir(year.path()).unwrap() {
+            let path = day.unwrap().path();
+            if path.extension().is_some_and(|ext| ext == "txt") {
+                let year = year.file_name().to_string_lossy().to_string();
+                let day = path.file_stem().unwrap().to_string_lossy().to_string();
+                inputs.push((year, day, path));
+            }
+        }
+    }
+    inputs.sort();
+
+    let mut table = String::from("pub static EMBEDDED_INPUTS: &[(&str, &str, &str)] = &[\n");
+    for (year, day, path) in inputs {
+        writeln!(table, "    ({:?}, {:?}, include_str!({:?})),", year, day, path.display().to_string()).unwrap();
+    }
+    table.push_str("];\n");
+
+    let out_path = Path::new(&env::var("OUT_DIR").unwrap()).join("embedded_inputs.rs");
+    fs::write(out_path, table).unwrap();
+}
@@ -0,0 +1,108 @@
+//!
+//! A terminal view of a year's progress, drawn as a grid of days like the event's calendar.
+//!
+
+use std::fmt::Write as _;
+
+use crate::report::{DayOutcome, DaySummary};
+use crate::utils::performance::format_duration;
+
+const COLUMNS: usize = 5;
+const CELL_WIDTH: usize = 11;
+
+const GOLD: &str = "\x1b[1;33m";
+const GREY: &str = "\x1b[90m";
+const GREEN: &str = "\x1b[32m";
+const RED: &str = "\x1b[31m";
+const RESET: &str = "\x1b[0m";
+
+/// Draws the calendar for `days`, using ANSI colours if `colour` is set.
+pub fn render(year: &str, days: &[DaySummary], colour: bool) -> String {
+    let paint = |text: String, code: &str| if colour { format!("{}{}{}", code, text, RESET) } else { text };
+    let border = |left: &str, middle: &str, right: &str, count: usize| {
+        format!("{}{}{}", left, vec!["─".repeat(CELL_WIDTH); count].join(middle), right)
+    };
+
+    let mut out = String::new();
+    let title = format!("Advent of Code {}", year);
+    let width = COLUMNS * (CELL_WIDTH + 1) + 1;
+    writeln!(out, "{}", paint(format!("{:^width$}", title), GREEN)).unwrap();
+
+    for (i, row) in days.chunks(COLUMNS).enumerate() {
+        let (left, middle, right) = if i == 0 { ("┌", "┬", "┐") } else { ("├", "┼", "┤") };
+        let previous_len = if i == 0 { row.len() } else { COLUMNS };
+        if row.len() == previous_len {
+            writeln!(out, "{}", border(left, middle, right, row.len())).unwrap();
+        } else {
+            // The last row is shorter, so close off the cells above it which have nothing below.
+            writeln!(out, "{}┼{}┘", border(left, middle, "", row.len()), vec!["─".repeat(CELL_WIDTH); COLUMNS - row.len()].join("┴")).unwrap();
+        }
+
+        let mut day_line = String::from("│");
+        let mut time_line = String::from("│");
+        for summary in row {
+            let day_number = summary.day.trim_start_matches('0');
+            let earned = summary.stars;
+            let star_text = format!(
+                "{}{}",
+                paint("★".repeat(earned), GOLD),
+                paint("☆".repeat(2 - earned), GREY)
+            );
+            let padding = CELL_WIDTH - 3 - 2 - 1;
+            write!(day_line, " {:>2} {}{}│", day_number, star_text, " ".repeat(padding)).unwrap();
+
+            let (time, code) = match &summary.outcome {
+                DayOutcome::Solved(solution) => (format_duration(solution.total_time()), GREY),
+                DayOutcome::Failed(_) => ("failed".to_string(), RED),
+                DayOutcome::NoInput => ("no input".to_string(), GREY),
+                DayOutcome::NoSolver => (String::new(), GREY),
+            };
+            write!(time_line, " {}│", paint(format!("{:<w$}", time, w = CELL_WIDTH - 1), code)).unwrap();
+        }
+        writeln!(out, "{}", day_line).unwrap();
+        writeln!(out, "{}", time_line).unwrap();
+    }
+
+    let last_len = days.len() - (days.len().saturating_sub(1) / COLUMNS) * COLUMNS;
+    writeln!(out, "{}", border("└", "┴", "┘", last_len)).unwrap();
+
+    let total: usize = days.iter().map(|summary| summary.stars).sum();
+    writeln!(out, "{}", paint(format!("{} of {} stars", total, days.len() * 2), GOLD)).unwrap();
+    out
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::tests::solved;
+
+    #[test]
+    fn render_without_colour() {
+        let days: Vec<_> = (1..=7).map(|d| solved(&format!("{:02}", d), 1, [Some(true), None])).collect();
+        let out = render("2025", &days, false);
+        let lines: Vec<&str> = out.lines().collect();
+
+        assert!(!out.contains('\x1b'));
+        assert_eq!(lines[1], "┌───────────┬───────────┬───────────┬───────────┬───────────┐");
+        assert_eq!(lines[2], "│  1 ★☆     │  2 ★☆     │  3 ★☆     │  4 ★☆     │  5 ★☆     │");
+        assert_eq!(lines[3], "│ 23.0 ms   │ 23.0 ms   │ 23.0 ms   │ 23.0 ms   │ 23.0 ms   │");
+        assert_eq!(lines[4], "├───────────┼───────────┼───────────┴───────────┴───────────┘");
+        assert_eq!(lines[7], "└───────────┴───────────┘");
+        assert_eq!(lines[8], "7 of 14 stars");
+    }
+
+    #[test]
+    fn render_stars_as_report_does() {
+        // Stars are the parts with a verified answer, even when the solver no longer gets it.
+        let out = render("2025", &[solved("01", 2, [Some(false), None])], false);
+        assert!(out.contains("│  1 ★★     │"));
+        assert!(out.contains("2 of 2 stars"));
+    }
+
+    #[test]
+    fn render_with_colour() {
+        let out = render("2025", &[solved("01", 2, [Some(true), Some(true)])], true);
+        assert!(out.contains(&format!("{}★★{}", GOLD, RESET)));
+    }
+}
@@ -0,0 +1,129 @@
+//!
+//! Checks that solvers give the same answers every time they run.
+//!
+//! Every `HashMap` gets new random keys, so each run can iterate them in a different order.
+//! Solvers marked `line_order_independent` also get their input lines shuffled, which shakes
+//! out sorts and searches that depend on the order ties arrive in.
+//!
+
+use std::fmt::Write as _;
+
+use crate::runner::{self, RunOptions};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DayDeterminism {
+    pub year: String,
+    pub day: String,
+    /// Whether the input lines were shuffled between runs.
+    pub shuffled: bool,
+    /// The answers from each run, or the error if the run failed.
+    pub runs: Vec<Result<(String, String), String>>,
+}
+
+impl DayDeterminism {
+    pub fn is_deterministic(&self) -> bool {
+        self.runs.windows(2).all(|pair| pair[0] == pair[1])
+    }
+}
+
+/// Runs the solver for a day `runs` times with `options`, or returns `None` if there is no solver.
+pub fn check(year: &str, day: &str, input: &str, runs: usize, options: &RunOptions) -> Option<DayDeterminism> {
+    let solver = runner::find_solver(year, day)?;
+    let shuffled = solver.line_order_independent();
+
+    let runs = (0..runs)
+        .map(|run| {
+            // The first run always gets the input as given, so it can be compared with the others.
+            let input = if shuffled && run > 0 { shuffle_lines(input, run as u64) } else { input.to_string() };
+            runner::run(year, day, &input, options)
+                .map(|result| (result.solution.one, result.solution.two))
+                .map_err(|err| err.to_string())
+        })
+        .collect();
+
+    Some(DayDeterminism { year: year.to_string(), day: day.to_string(), shuffled, runs })
+}
+
+/// Reorders the lines of `input` with a fixed seed, keeping a trailing newline if there was one.
+pub fn shuffle_lines(input: &str, seed: u64) -> String {
+    let mut lines: Vec<&str> = input.lines().collect();
+
+    // Fisher-Yates with a linear congruential generator, which is plenty random for this.
+    let mut state = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+    for i in (1..lines.len()).rev() {
+        state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        let j = ((state >> 33) % (i as u64 + 1)) as usize;
+        lines.swap(i, j);
+    }
+
+    let mut out = lines.join("\n");
+    if input.ends_with('\n') {
+        out.push('\n');
+    }
+    out
+}
+
+/// Describes a day's result, listing every run's answers when they differ.
+pub fn render(result: &DayDeterminism) -> String {
+    let how = if result.shuffled { ", shuffling lines" } else { "" };
+    let mut out = String::new();
+
+    if result.is_deterministic() {
+        write!(out, "{} day {}: same answers over {} runs{}", result.year, result.day, result.runs.len(), how).unwrap();
+        return out;
+    }
+
+    write!(out, "{} day {}: answers differ over {} runs{}", result.year, result.day, result.runs.len(), how).unwrap();
+    for (i, run) in result.runs.iter().enumerate() {
+        match run {
+            Ok((one, two)) => write!(out, "\n  run {}: {} / {}", i + 1, one, two).unwrap(),
+            Err(err) => write!(out, "\n  run {}: {}", i + 1, err).unwrap(),
+        }
+    }
+    out
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shuffle_keeps_lines() {
+        let input = "a\nb\nc\nd\ne\n";
+        let shuffled = shuffle_lines(input, 3);
+        assert_ne!(shuffled, input);
+        assert!(shuffled.ends_with('\n'));
+
+        let mut lines: Vec<&str> = shuffled.lines().collect();
+        lines.sort();
+        assert_eq!(lines, vec!["a", "b", "c", "d", "e"]);
+        assert_eq!(shuffle_lines(input, 3), shuffled);
+    }
+
+    #[test]
+    #[cfg(feature = "year2025")]
+    fn check_order_independent_day() {
+        let result = check("2025", "03", "987654321111111\n811111111111119\n234234234234278\n818181911112111\n", 4, &RunOptions::default()).unwrap();
+        assert!(result.shuffled);
+        assert!(result.is_deterministic());
+        assert_eq!(result.runs[0], Ok(("357".to_string(), "3121910778619".to_string())));
+    }
+
+    #[test]
+    fn render_differences() {
+        let result = DayDeterminism {
+            year: "2025".to_string(),
+            day: "08".to_string(),
+            shuffled: true,
+            runs: vec![Ok(("1".to_string(), "2".to_string())), Ok(("1".to_string(), "3".to_string()))],
+        };
+        assert!(!result.is_deterministic());
+        assert_eq!(render(&result), "2025 day 08: answers differ over 2 runs, shuffling lines\n  run 1: 1 / 2\n  run 2: 1 / 3");
+    }
+
+    #[test]
+    fn check_without_solver() {
+        assert!(check("1999", "01", "", 2, &RunOptions::default()).is_none());
+    }
+}
@@ -0,0 +1,305 @@
+//!
+//! Running a solver in a child process, so that even a stack overflow, which aborts the
+//! whole process, is reported as a failure for that day rather than ending every run.
+//!
+//! The child is this same binary, re-executed with `CHILD_ARG`, or the one given by
+//! `RunOptions::child_binary`. It reads the input from stdin, runs the solver as usual, and
+//! writes the result to stdout as a line of JSON after anything the solver printed.
+//!
+
+use std::env;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::process::{self, Command, ExitStatus, Stdio};
+use std::sync::{mpsc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use crate::runner::{self, RunError, RunOptions, RunResult};
+use crate::utils::json::Json;
+use crate::utils::log::{self, Level};
+use crate::utils::parse::Diagnostic;
+use crate::utils::solver::Solution;
+use crate::utils::trace::Event;
+
+/// The argument which makes the binary run one solver as the child of an isolated run.
+/// A binary which runs solvers with `RunOptions::isolate` should call `run_child` when it
+/// is given this as its first argument.
+pub const CHILD_ARG: &str = "__run_solver_child";
+
+/// Which solver the child should run, and how, as `year day stack_size trace print level`.
+const CHILD_ENV: &str = "ADVENT_SOLVER_CHILD";
+
+/// Starts the line the child writes its result on, to tell it apart from the solver's output.
+const RESULT_MARKER: &str = "\u{1e}advent-result ";
+
+/// Runs a solver in a child process, waiting for it as `runner::run` does for a thread.
+pub fn run(year: &str, day: &str, input: &str, options: &RunOptions) -> Result<RunResult, RunError> {
+    let failed = |message: String| RunError::Panicked { year: year.to_string(), day: day.to_string(), message };
+    let spec = format!(
+        "{} {} {} {} {} {}",
+        year,
+        day,
+        options.stack_size.unwrap_or(runner::DEFAULT_STACK_SIZE),
+        options.trace,
+        options.print,
+        log::level()
+    );
+
+    let exe = match &options.child_binary {
+        Some(binary) => binary.clone(),
+        None => env::current_exe().map_err(|err| failed(format!("could not find the binary to run the solver with: {}", err)))?,
+    };
+    let mut child = Command::new(exe)
+        .arg(CHILD_ARG)
+        .env(CHILD_ENV, spec)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|err| failed(format!("could not start the solver process: {}", err)))?;
+
+    let mut stdin = child.stdin.take().expect("child stdin is piped");
+    let input = input.to_string();
+    thread::spawn(move || {
+        // If the child has already died there is nobody to give the input to.
+        let _ = stdin.write_all(input.as_bytes());
+    });
+
+    // Anything the solver prints is passed on as it arrives, keeping back the result line.
+    let stdout = child.stdout.take().expect("child stdout is piped");
+    let print = options.print;
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let mut result = None;
+        for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+            // The marker may follow output which didn't end in a newline.
+            match line.split_once(RESULT_MARKER) {
+                Some((output, json)) => {
+                    if print && !output.is_empty() {
+                        println!("{}", output);
+                    }
+                    result = Some(json.to_string());
+                },
+                None if print => println!("{}", line),
+                None => {},
+            }
+        }
+        let _ = sender.send(result);
+    });
+
+    // Stderr is passed on too, watching for the message Rust prints before aborting on a
+    // stack overflow, as the signal alone doesn't say why the process died.
+    let stderr = child.stderr.take().expect("child stderr is piped");
+    let overflowed = thread::spawn(move || {
+        let mut overflowed = false;
+        for line in BufReader::new(stderr).lines().map_while(Result::ok) {
+            overflowed |= line.contains("has overflowed its stack");
+            eprintln!("{}", line);
+        }
+        overflowed
+    });
+
+    let result = match options.timeout {
+        None => receiver.recv().ok().flatten(),
+        Some(timeout) => match receiver.recv_timeout(timeout) {
+            Ok(result) => result,
+            Err(mpsc::RecvTimeoutError::Timeout) => {
+                // Unlike a thread, a process can be stopped, so it doesn't keep running.
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err(RunError::TimedOut { year: year.to_string(), day: day.to_string(), timeout });
+            },
+            Err(mpsc::RecvTimeoutError::Disconnected) => None,
+        },
+    };
+    let status = child.wait().map_err(|err| failed(format!("could not wait for the solver process: {}", err)))?;
+    let overflowed = overflowed.join().unwrap_or(false);
+
+    match result {
+        Some(json) => result_from_json(&json).unwrap_or_else(|| Err(failed(format!("could not read the solver's result {}", json)))),
+        None if overflowed => Err(RunError::StackOverflow { year: year.to_string(), day: day.to_string() }),
+        None => Err(failed(exit_message(status))),
+    }
+}
+
+/// Describes how a process which gave no result ended.
+#[cfg(unix)]
+fn exit_message(status: ExitStatus) -> String {
+    use std::os::unix::process::ExitStatusExt;
+
+    match status.signal() {
+        Some(signal) => format!("solver process was killed by signal {}", signal),
+        None => format!("solver process exited without a result ({})", status),
+    }
+}
+
+#[cfg(not(unix))]
+fn exit_message(status: ExitStatus) -> String {
+    format!("solver process exited without a result ({})", status)
+}
+
+/// Runs the solver the parent asked for and writes its result to stdout, then exits.
+pub fn run_child() -> ! {
+    let spec = env::var(CHILD_ENV).unwrap_or_default();
+    let [year, day, stack_size, trace, print, level] = spec.split(' ').collect::<Vec<_>>()[..] else {
+        eprintln!("{} should give the solver to run", CHILD_ENV);
+        process::exit(2);
+    };
+    log::set_level(level.parse().unwrap_or(Level::Normal));
+
+    let mut input = String::new();
+    if let Err(err) = io::stdin().read_to_string(&mut input) {
+        eprintln!("Could not read the input: {}", err);
+        process::exit(2);
+    }
+
+    let options = RunOptions {
+        print: print == "true",
+        trace: trace == "true",
+        stack_size: stack_size.parse().ok(),
+        ..RunOptions::default()
+    };
+    let result = runner::run(year, day, &input, &options);
+
+    // Flushed by hand, as `process::exit` doesn't.
+    let mut stdout = io::stdout().lock();
+    let _ = writeln!(stdout, "{}{}", RESULT_MARKER, result_to_json(year, day, &result));
+    let _ = stdout.flush();
+    process::exit(0);
+}
+
+fn result_to_json(year: &str, day: &str, result: &Result<RunResult, RunError>) -> Json {
+    let outcome = match result {
+        Ok(result) => ("solution", Json::object([
+            ("one", Json::from(result.solution.one.as_str())),
+            ("two", Json::from(result.solution.two.as_str())),
+            ("preprocess", Json::Int(result.solution.preprocess_time.as_nanos() as i128)),
+            ("part_one", Json::Int(result.solution.part_one_time.as_nanos() as i128)),
+            ("part_two", Json::Int(result.solution.part_two_time.as_nanos() as i128)),
+            ("allocations", Json::from(result.allocations)),
+            ("events", Json::Array(result.events.iter().enumerate().map(|(seq, event)| event.to_json(seq)).collect())),
+        ])),
+        Err(RunError::InvalidInput { diagnostic, .. }) => ("diagnostic", diagnostic_to_json(diagnostic)),
+        Err(RunError::Panicked { message, .. }) => ("panic", Json::from(message.as_str())),
+        // The child has no timeout and only runs solvers that exist, so these are unexpected.
+        Err(err) => ("panic", Json::from(err.to_string())),
+    };
+    Json::object([("year", Json::from(year)), ("day", Json::from(day)), outcome])
+}
+
+fn result_from_json(text: &str) -> Option<Result<RunResult, RunError>> {
+    let json = Json::parse(text).ok()?;
+    let string = |json: &Json, key: &str| json.get(key).and_then(Json::as_str).map(String::from);
+    let nanos = |json: &Json, key: &str| json.get(key).and_then(Json::as_int).map(|n| Duration::from_nanos(n as u64));
+    let (year, day) = (string(&json, "year")?, string(&json, "day")?);
+
+    if let Some(result) = json.get("solution") {
+        let solution = Solution {
+            one: string(result, "one")?,
+            two: string(result, "two")?,
+            preprocess_time: nanos(result, "preprocess")?,
+            part_one_time: nanos(result, "part_one")?,
+            part_two_time: nanos(result, "part_two")?,
+        };
+        let allocations = result.get("allocations").and_then(Json::as_int).map(|n| n as u64);
+        let events = result.get("events")?.as_array()?.iter().map(event_from_json).collect::<Option<_>>()?;
+        return Some(Ok(RunResult { year, day, solution, allocations, events }));
+    }
+    if let Some(diagnostic) = json.get("diagnostic") {
+        let diagnostic = Box::new(diagnostic_from_json(diagnostic)?);
+        return Some(Err(RunError::InvalidInput { year, day, diagnostic }));
+    }
+    let message = string(&json, "panic")?;
+    Some(Err(RunError::Panicked { year, day, message }))
+}
+
+fn diagnostic_to_json(diagnostic: &Diagnostic) -> Json {
+    Json::object([
+        ("year", Json::from(diagnostic.year.clone())),
+        ("day", Json::from(diagnostic.day.clone())),
+        ("message", Json::from(diagnostic.message.as_str())),
+        ("line", Json::from(diagnostic.line)),
+        ("column", Json::from(diagnostic.column)),
+        ("width", Json::from(diagnostic.width)),
+        ("source_line", Json::from(diagnostic.source_line.as_str())),
+        ("hint", Json::from(diagnostic.hint.clone())),
+    ])
+}
+
+fn diagnostic_from_json(json: &Json) -> Option<Diagnostic> {
+    let string = |key: &str| json.get(key).and_then(Json::as_str).map(String::from);
+    let number = |key: &str| json.get(key).and_then(Json::as_int).map(|n| n as usize);
+    Some(Diagnostic {
+        year: string("year"),
+        day: string("day"),
+        message: string("message")?,
+        line: number("line")?,
+        column: number("column")?,
+        width: number("width")?,
+        source_line: string("source_line")?,
+        hint: string("hint"),
+    })
+}
+
+fn event_from_json(json: &Json) -> Option<Event> {
+    let name = intern(json.get("name")?.as_str()?);
+    let Json::Object(fields) = json.get("fields")? else {
+        return None;
+    };
+    let fields = fields.iter().map(|(key, value)| (intern(key), value.clone())).collect();
+    Some(Event { name, fields })
+}
+
+/// Event names and keys are `&'static str`, as solvers give them as literals. Those read
+/// back from a child are leaked, once for each distinct name, of which there are few.
+fn intern(name: &str) -> &'static str {
+    static NAMES: Mutex<Vec<&'static str>> = Mutex::new(Vec::new());
+
+    let mut names = NAMES.lock().unwrap_or_else(|err| err.into_inner());
+    if let Some(&interned) = names.iter().find(|&&interned| interned == name) {
+        return interned;
+    }
+    let interned: &'static str = Box::leak(name.to_string().into_boxed_str());
+    names.push(interned);
+    interned
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn result_round_trip() {
+        let result = Ok(RunResult {
+            year: "2025".to_string(),
+            day: "03".to_string(),
+            solution: Solution {
+                one: "1".to_string(),
+                two: "two\nlines".to_string(),
+                preprocess_time: Duration::from_nanos(5),
+                part_one_time: Duration::from_micros(3),
+                part_two_time: Duration::from_secs(2),
+            },
+            allocations: Some(7),
+            events: vec![Event { name: "pivot", fields: vec![("row", Json::from(1u8))] }],
+        });
+        assert_eq!(result_from_json(&result_to_json("2025", "03", &result).to_string()), Some(result));
+
+        let diagnostic = Box::new(Diagnostic::at("ab\n", 1, "bad").with_hint("fix it").for_day("2025", "07"));
+        let result = Err(RunError::InvalidInput { year: "2025".to_string(), day: "07".to_string(), diagnostic });
+        assert_eq!(result_from_json(&result_to_json("2025", "07", &result).to_string()), Some(result));
+
+        let result = Err(RunError::Panicked { year: "2025".to_string(), day: "11".to_string(), message: "oops".to_string() });
+        assert_eq!(result_from_json(&result_to_json("2025", "11", &result).to_string()), Some(result));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn exit_message_names_signal() {
+        use std::os::unix::process::ExitStatusExt;
+
+        assert_eq!(exit_message(ExitStatus::from_raw(6)), "solver process was killed by signal 6");
+        assert_eq!(exit_message(ExitStatus::from_raw(3 << 8)), "solver process exited without a result (exit status: 3)");
+    }
+}
@@ -1,2 +1,16 @@
 pub mod utils;
-pub mod year_2025;
\ No newline at end of file
+#[cfg(feature = "year2025")]
+pub mod year_2025;
+pub mod serve;
+pub mod metrics;
+pub mod report;
+pub mod calendar;
+pub mod status;
+pub mod determinism;
+pub mod repl;
+mod runner;
+mod isolation;
+
+pub use advent_utils::{define_solver, log, parse_pattern, time_execution};
+pub use runner::{find_solver, run, solvers, RunError, RunOptions, RunResult, DEFAULT_STACK_SIZE};
+pub use isolation::{run_child, CHILD_ARG};
\ No newline at end of file
@@ -1,53 +1,311 @@
 use std::env;
 use std::error::Error;
-use std::time;
+use std::fs;
+use std::io::{self, IsTerminal};
+use std::time::Duration;
 
-mod year_2025;
-mod utils;
+use advent::{calendar, determinism, find_solver, metrics, repl, report, run, run_child, status, RunError, RunOptions, CHILD_ARG};
+use advent::serve::{serve, ServeOptions};
+use advent::time_execution;
+use advent::utils::alloc::CountingAllocator;
+use advent::utils::answers::ExpectedAnswers;
+use advent::utils::inputs::ProblemInputs;
+use advent::utils::json::Json;
+use advent::utils::log::{self, Level};
+use advent::utils::solver::Part;
+use advent::utils::trace::{self, Event};
+use advent::utils::submit::{Ledger, SubmitOutcome, Submitter, DEFAULT_ENDPOINT};
 
-use utils::inputs::ProblemInputs;
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
 
 fn main() -> Result<(), Box<dyn Error>> {
-    let mut args = env::args();
-    let year = args.nth(1);
-    let day = args.next();
-
-    let problem_inputs = ProblemInputs::load_from_path("./inputs")?;
-    let inputs = problem_inputs.get(year.as_deref(), day.as_deref());
-
-    let solutions: Vec<Box<dyn utils::solver::SolverDyn>> = vec![
-        Box::new(year_2025::day_01::Day1Solver),
-        Box::new(year_2025::day_02::Day2Solver),
-        Box::new(year_2025::day_03::Day3Solver),
-        Box::new(year_2025::day_04::Day4Solver),
-        Box::new(year_2025::day_05::Day5Solver),
-        Box::new(year_2025::day_06::Day6Solver),
-        Box::new(year_2025::day_07::Day7Solver),
-        Box::new(year_2025::day_08::Day8Solver),
-        Box::new(year_2025::day_09::Day9Solver),
-        Box::new(year_2025::day_10::Day10Solver),
-        Box::new(year_2025::day_11::Day11Solver),
-        Box::new(year_2025::day_12::Day12Solver),
-    ];
-
-    let mut count = 0;
-    time_execution!(format!("{} solvers", count), {
+    // Isolated runs re-execute this binary to run a single solver in a child process.
+    if env::args().nth(1).as_deref() == Some(CHILD_ARG) {
+        run_child();
+    }
+    let args = set_log_level(env::args().skip(1).collect())?;
+
+    match args.first().map(String::as_str) {
+        Some("submit") => submit(&args[1..]),
+        Some("report") => write_report(&args[1..]),
+        Some("calendar") => print_calendar(&args[1..]),
+        Some("status") => print_status(),
+        Some("serve") => serve(&serve_options(&args[1..])?),
+        Some("determinism") => check_determinism(&args[1..]),
+        Some("repl") => start_repl(&args[1..]),
+        _ => run_all(&args),
+    }
+}
+
+/// Sets the log level from `-q`/`--quiet`, `-v`/`--verbose`, `--trace` or `--log <level>`,
+/// returning the remaining arguments.
+fn set_log_level(args: Vec<String>) -> Result<Vec<String>, Box<dyn Error>> {
+    let mut remaining = vec![];
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-q" | "--quiet" => log::set_level(Level::Quiet),
+            "-v" | "--verbose" => log::set_level(Level::Verbose),
+            "--trace" => log::set_level(Level::Trace),
+            "--log" => log::set_level(args.next().ok_or("--log requires a level")?.parse()?),
+            _ => remaining.push(arg),
+        }
+    }
+    Ok(remaining)
+}
+
+/// Runs every solver matching `[year] [day]`, optionally writing metrics with `--metrics <path>`
+/// and trace events as JSON lines with `--events <path> [--events-filter <name,name>]`.
+/// At the trace log level, events are also printed to stderr as each solver finishes.
+/// `--stack-size <MiB>` sets the stack size of the solver threads.
+fn run_all(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let mut positional = vec![];
+    let mut metrics_path = None;
+    let mut events_path = None;
+    let mut events_filter = None;
+    let mut stack_size = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--metrics" => metrics_path = Some(args.next().ok_or("--metrics requires a path")?),
+            "--events" => events_path = Some(args.next().ok_or("--events requires a path")?),
+            "--events-filter" => events_filter = Some(args.next().ok_or("--events-filter requires event names")?),
+            "--stack-size" => {
+                let mebibytes: usize = args.next().ok_or("--stack-size requires a size in MiB")?.parse()?;
+                stack_size = Some(mebibytes.checked_mul(1024 * 1024).ok_or("--stack-size is too large")?);
+            },
+            arg => positional.push(arg),
+        }
+    }
+
+    let problem_inputs = ProblemInputs::load()?;
+    let inputs = problem_inputs.get(positional.first().copied(), positional.get(1).copied());
+    let print_events = log::enabled(Level::Trace);
+    let options = RunOptions { print: true, trace: events_path.is_some() || print_events, stack_size, isolate: true, ..RunOptions::default() };
+
+    let mut results = vec![];
+    time_execution!(format!("{} solvers", results.len()), {
         for input in inputs {
             let input_text = input.get_text()?;
 
-            solutions
-                .iter()
-                .filter(|&solver| {
-                    let solver = solver.as_ref();
-                    solver.year() == input.year && solver.day() == input.day
-                })
-                .for_each(|solver| {
-                    count += 1;
-                    solver.as_ref().solve(&input_text);
-                });
-        }
-        print!("\n")
+            match run(&input.year, &input.day, &input_text, &options) {
+                Err(RunError::NoSolver { .. }) => {},
+                Err(err @ (RunError::InvalidInput { .. } | RunError::StackOverflow { .. })) => {
+                    eprintln!("{}", err);
+                    results.push(Err(err));
+                },
+                Ok(result) if print_events => {
+                    for (seq, event) in result.events.iter().enumerate() {
+                        eprintln!("trace {} day {} {}", result.year, result.day, event.to_json(seq));
+                    }
+                    results.push(Ok(result));
+                },
+                result => results.push(result),
+            }
+        }
+        advent::log!(Level::Normal, "")
     });
 
+    if let Some(path) = events_path {
+        let names: Option<Vec<&str>> = events_filter.map(|names| names.split(',').collect());
+        let mut lines = String::new();
+        for result in results.iter().flatten() {
+            let events: Vec<&Event> = match &names {
+                Some(names) => trace::filter(&result.events, names).collect(),
+                None => result.events.iter().collect(),
+            };
+            for (seq, event) in events.into_iter().enumerate() {
+                let json = Json::object([
+                    ("year", Json::from(result.year.as_str())),
+                    ("day", Json::from(result.day.as_str())),
+                    ("event", event.to_json(seq)),
+                ]);
+                lines.push_str(&format!("{}\n", json));
+            }
+        }
+        fs::write(path, lines)?;
+    }
+
+    if let Some(path) = metrics_path {
+        let answers = ExpectedAnswers::load_from_path("./answers")?;
+        metrics::write(path, &results, &answers)?;
+    }
+
+    for result in results {
+        result?;
+    }
+    Ok(())
+}
+
+/// Solves a single part and submits the answer, unless the ledger already shows it is wrong.
+///
+/// The session cookie is read from `AOC_SESSION`, and `AOC_ENDPOINT` can be set to submit
+/// somewhere other than adventofcode.com.
+fn submit(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let [year, day, part] = args else {
+        return Err("Usage: submit <year> <day> <part>".into());
+    };
+    let part: Part = part.parse()?;
+
+    let problem_inputs = ProblemInputs::load()?;
+    let input = problem_inputs.find(year, day).ok_or_else(|| format!("No input for year {} day {}", year, day))?;
+    let result = run(year, day, &input.get_text()?, &RunOptions { print: true, isolate: true, ..RunOptions::default() })?;
+    let answer = result.solution.get(part).to_string();
+
+    let mut expected_answers = ExpectedAnswers::load_from_path("./answers")?;
+    let mut ledger = Ledger::load_from_path("./answers/ledger.tsv")?;
+
+    if let Some(expected) = expected_answers.get_part(year, day, part) {
+        if expected == answer {
+            println!("Answer {} is already verified for year {} day {} part {}", answer, year, day, part);
+        } else {
+            println!("Refusing to submit {}, the verified answer is {}", answer, expected);
+        }
+        return Ok(());
+    }
+
+    if let Some(attempt) = ledger.known_wrong(year, day, part, &answer) {
+        println!(
+            "Refusing to submit {}, {} was submitted previously with result: {}",
+            answer, attempt.answer, attempt.outcome
+        );
+        return Ok(());
+    }
+
+    let Ok(session) = env::var("AOC_SESSION") else {
+        return Err("AOC_SESSION should be set to your session cookie".into());
+    };
+    let submitter = Submitter {
+        endpoint: env::var("AOC_ENDPOINT").unwrap_or(DEFAULT_ENDPOINT.to_string()),
+        session,
+    };
+
+    let outcome = submitter.submit(year, day, part, &answer)?;
+    println!("Submitted {}: {}", answer, outcome);
+
+    ledger.record(year, day, part, &answer, outcome.clone())?;
+    if outcome == SubmitOutcome::Correct {
+        expected_answers.set(year, day, part, &answer)?;
+    }
+
+    Ok(())
+}
+
+/// Writes Markdown and HTML summaries of a year to `/reports/yyyy.md` and `/reports/yyyy.html`.
+fn write_report(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let [year] = args else {
+        return Err("Usage: report <year>".into());
+    };
+
+    let problem_inputs = ProblemInputs::load()?;
+    let answers = ExpectedAnswers::load_from_path("./answers")?;
+    let days = report::summarise_year(year, &problem_inputs, &answers, &RunOptions { isolate: true, ..RunOptions::default() });
+
+    fs::create_dir_all("./reports")?;
+    fs::write(format!("./reports/{}.md", year), report::markdown(year, &days))?;
+    fs::write(format!("./reports/{}.html", year), report::html(year, &days))?;
+    println!("Wrote reports/{}.md and reports/{}.html", year, year);
+    Ok(())
+}
+
+/// Draws a year's stars and runtimes, in colour when writing to a terminal.
+fn print_calendar(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let [year] = args else {
+        return Err("Usage: calendar <year>".into());
+    };
+
+    let problem_inputs = ProblemInputs::load()?;
+    let answers = ExpectedAnswers::load_from_path("./answers")?;
+    let days = report::summarise_year(year, &problem_inputs, &answers, &RunOptions { isolate: true, ..RunOptions::default() });
+
+    let colour = io::stdout().is_terminal() && env::var_os("NO_COLOR").is_none();
+    print!("{}", calendar::render(year, &days, colour));
+    Ok(())
+}
+
+/// Prints which days have a solver, input, tests and verified answers, and anything orphaned.
+fn print_status() -> Result<(), Box<dyn Error>> {
+    let problem_inputs = ProblemInputs::load()?;
+    let answers = ExpectedAnswers::load_from_path("./answers")?;
+
+    for year in status::years(&problem_inputs, &answers) {
+        let days = status::year_status(&year, &problem_inputs, &answers);
+        println!("{}", status::render(&year, &days));
+    }
+    for warning in status::orphans(&problem_inputs, &answers) {
+        println!("Warning: {}", warning);
+    }
     Ok(())
-}
\ No newline at end of file
+}
+
+/// Runs every solver matching `[year] [day]` several times (`--runs <count>`, 5 by default),
+/// failing if any day's answers change between runs.
+fn check_determinism(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let mut positional = vec![];
+    let mut runs = 5;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--runs" => runs = args.next().ok_or("--runs requires a count")?.parse()?,
+            arg => positional.push(arg),
+        }
+    }
+
+    let problem_inputs = ProblemInputs::load()?;
+    let options = RunOptions { isolate: true, ..RunOptions::default() };
+    let mut differing = vec![];
+    for input in problem_inputs.get(positional.first().copied(), positional.get(1).copied()) {
+        let Some(result) = determinism::check(&input.year, &input.day, &input.get_text()?, runs, &options) else {
+            continue;
+        };
+        println!("{}", determinism::render(&result));
+        if !result.is_deterministic() {
+            differing.push(format!("{} day {}", result.year, result.day));
+        }
+    }
+
+    if differing.is_empty() {
+        Ok(())
+    } else {
+        Err(format!("Answers differ between runs for {}", differing.join(", ")).into())
+    }
+}
+
+/// Opens a prompt for running a day's parts against its preprocessed input.
+fn start_repl(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let [year, day] = args else {
+        return Err("Usage: repl <year> <day>".into());
+    };
+    let Some(solver) = find_solver(year, day) else {
+        return Err(RunError::NoSolver { year: year.to_string(), day: day.to_string() }.into());
+    };
+
+    // Look the inputs up again on every load, so a reload picks up a newly added input.
+    let load = || ProblemInputs::load()?
+        .find(year, day)
+        .ok_or(format!("No input found for year {} day {}", year, day))?
+        .get_text();
+    repl::repl(solver, load, io::stdin().lock(), io::stdout())
+}
+
+/// Reads `[port] [--timeout <seconds>]` for the serve command.
+fn serve_options(args: &[String]) -> Result<ServeOptions, Box<dyn Error>> {
+    let mut options = ServeOptions { isolate: true, ..ServeOptions::default() };
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--timeout" => {
+                let seconds: u64 = args.next().ok_or("--timeout requires a number of seconds")?.parse()?;
+                options.timeout = Duration::from_secs(seconds);
+            },
+            port => {
+                let port: u16 = port.parse()?;
+                options.addr = format!("127.0.0.1:{}", port);
+            },
+        }
+    }
+    Ok(options)
+}
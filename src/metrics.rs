@@ -0,0 +1,125 @@
+//!
+//! Export of solver timings in the OpenMetrics text format, to be picked up by
+//! a node-exporter textfile collector or similar.
+//!
+
+use std::error::Error;
+use std::fmt::Write as _;
+use std::fs;
+
+use crate::runner::{RunError, RunResult};
+use crate::utils::answers::ExpectedAnswers;
+use crate::utils::solver::Part;
+
+/// Renders the metrics for a set of runs. Runs with no solver are left out.
+pub fn render(results: &[Result<RunResult, RunError>], answers: &ExpectedAnswers) -> String {
+    let mut durations = String::new();
+    let mut successes = String::new();
+    let mut checks = String::new();
+    let mut allocations = String::new();
+
+    for result in results {
+        match result {
+            Ok(RunResult { year, day, solution, allocations: allocation_count, .. }) => {
+                let steps = [
+                    ("preprocess", solution.preprocess_time),
+                    ("1", solution.part_one_time),
+                    ("2", solution.part_two_time),
+                ];
+                for (part, duration) in steps {
+                    writeln!(durations, "aoc_solver_duration_seconds{{year=\"{}\",day=\"{}\",part=\"{}\"}} {}", year, day, part, duration.as_secs_f64()).unwrap();
+                }
+                writeln!(successes, "aoc_solver_success{{year=\"{}\",day=\"{}\"}} 1", year, day).unwrap();
+
+                for part in [Part::One, Part::Two] {
+                    if let Some(correct) = answers.check(year, day, part, solution.get(part)) {
+                        writeln!(checks, "aoc_solver_answer_correct{{year=\"{}\",day=\"{}\",part=\"{}\"}} {}", year, day, part, correct as u8).unwrap();
+                    }
+                }
+                if let Some(count) = allocation_count {
+                    writeln!(allocations, "aoc_solver_allocations{{year=\"{}\",day=\"{}\"}} {}", year, day, count).unwrap();
+                }
+            },
+            Err(RunError::NoSolver { .. }) => {},
+            Err(err) => {
+                writeln!(successes, "aoc_solver_success{{year=\"{}\",day=\"{}\"}} 0", err.year(), err.day()).unwrap();
+            },
+        }
+    }
+
+    let mut out = String::new();
+    let families = [
+        ("aoc_solver_duration_seconds", "Time taken by each step of a solver.", durations),
+        ("aoc_solver_success", "Whether the solver finished without panicking or timing out.", successes),
+        ("aoc_solver_answer_correct", "Whether the answer matches the verified answer.", checks),
+        ("aoc_solver_allocations", "Heap allocations made while solving.", allocations),
+    ];
+    for (name, help, samples) in families {
+        if samples.is_empty() {
+            continue;
+        }
+        writeln!(out, "# HELP {} {}", name, help).unwrap();
+        writeln!(out, "# TYPE {} gauge", name).unwrap();
+        out.push_str(&samples);
+    }
+    out.push_str("# EOF\n");
+    out
+}
+
+/// Writes the metrics to `path`, replacing it in one step so collectors never see a partial file.
+pub fn write(path: &str, results: &[Result<RunResult, RunError>], answers: &ExpectedAnswers) -> Result<(), Box<dyn Error>> {
+    let tmp_path = format!("{}.tmp", path);
+    fs::write(&tmp_path, render(results, answers))?;
+    fs::rename(&tmp_path, path)?;
+    Ok(())
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+    use crate::utils::solver::Solution;
+
+    fn result(day: &str) -> RunResult {
+        RunResult {
+            year: "2025".to_string(),
+            day: day.to_string(),
+            solution: Solution {
+                one: "1".to_string(),
+                two: "2".to_string(),
+                preprocess_time: Duration::from_millis(1),
+                part_one_time: Duration::from_millis(2),
+                part_two_time: Duration::from_millis(500),
+            },
+            allocations: Some(10),
+            events: vec![],
+        }
+    }
+
+    #[test]
+    fn render_success() {
+        let answers = ExpectedAnswers::load_from_path("./does-not-exist").unwrap();
+        let out = render(&[Ok(result("01"))], &answers);
+        assert!(out.contains("# TYPE aoc_solver_duration_seconds gauge\n"));
+        assert!(out.contains("aoc_solver_duration_seconds{year=\"2025\",day=\"01\",part=\"preprocess\"} 0.001\n"));
+        assert!(out.contains("aoc_solver_duration_seconds{year=\"2025\",day=\"01\",part=\"2\"} 0.5\n"));
+        assert!(out.contains("aoc_solver_success{year=\"2025\",day=\"01\"} 1\n"));
+        assert!(out.contains("aoc_solver_allocations{year=\"2025\",day=\"01\"} 10\n"));
+        assert!(!out.contains("aoc_solver_answer_correct"));
+        assert!(out.ends_with("# EOF\n"));
+    }
+
+    #[test]
+    fn render_failure() {
+        let answers = ExpectedAnswers::load_from_path("./does-not-exist").unwrap();
+        let results = [
+            Err(RunError::Panicked { year: "2025".to_string(), day: "02".to_string(), message: String::new() }),
+            Err(RunError::NoSolver { year: "2025".to_string(), day: "03".to_string() }),
+        ];
+        let out = render(&results, &answers);
+        assert!(out.contains("aoc_solver_success{year=\"2025\",day=\"02\"} 0\n"));
+        assert!(!out.contains("day=\"03\""));
+        assert!(!out.contains("aoc_solver_duration_seconds"));
+    }
+}
@@ -0,0 +1,246 @@
+//!
+//! An interactive prompt for exploring a day's preprocessed input.
+//!
+//! The input is preprocessed once, then the parts can be run against it as often as needed
+//! and the parsed value printed. `reload` reads the input again, eg. after trimming it down.
+//! If the new input is rejected, or preprocessing it panics, the previous one is kept, so
+//! the session carries on.
+//!
+
+use std::error::Error;
+use std::io::{BufRead, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread;
+use std::time::Duration;
+
+use crate::runner::{panic_message, DEFAULT_STACK_SIZE};
+use crate::utils::performance::{format_duration, measure};
+use crate::utils::solver::{Part, Session, SolverDyn};
+
+const HELP: &str = "\
+Commands:
+  1          run part 1
+  2          run part 2
+  inspect    print the preprocessed input
+  reload     read the input again and preprocess it
+  help       show this message
+  quit       exit";
+
+/// Work for the solver thread, which owns the session.
+enum Job {
+    Preprocess(String),
+    Run(Part),
+    Inspect,
+}
+
+/// What the solver thread did, with panics and rejected inputs as messages.
+enum Done {
+    Preprocessed(Result<Duration, String>),
+    Ran(Result<(String, Duration), String>),
+    Inspected(String),
+}
+
+/// Runs the prompt until `quit` or the end of `commands`, loading the input with `load`.
+///
+/// The solver runs on its own thread with the runner's stack size, so deep recursion works
+/// as it does in a normal run.
+pub fn repl(
+    solver: &dyn SolverDyn,
+    load: impl Fn() -> Result<String, Box<dyn Error>>,
+    commands: impl BufRead,
+    mut out: impl Write,
+) -> Result<(), Box<dyn Error>> {
+    thread::scope(|scope| {
+        let (jobs, job_receiver) = mpsc::channel();
+        let (done_sender, done) = mpsc::channel();
+        thread::Builder::new()
+            .name(format!("{} day {}", solver.year(), solver.day()))
+            .stack_size(DEFAULT_STACK_SIZE)
+            .spawn_scoped(scope, move || solver_thread(solver, job_receiver, done_sender))?;
+
+        // Dropping `jobs` on the way out ends the solver thread.
+        let ask = |job| -> Result<Done, Box<dyn Error>> {
+            jobs.send(job)?;
+            Ok(done.recv()?)
+        };
+
+        let input = load()?;
+        preprocess(ask(Job::Preprocess(input.clone()))?, &input, &mut out)?;
+        prompt(solver, &mut out)?;
+
+        for command in commands.lines() {
+            match command?.trim() {
+                "" => {},
+                "1" => run_part(ask(Job::Run(Part::One))?, &mut out)?,
+                "2" => run_part(ask(Job::Run(Part::Two))?, &mut out)?,
+                "inspect" | "i" => match ask(Job::Inspect)? {
+                    Done::Inspected(text) => writeln!(out, "{}", text)?,
+                    _ => unreachable!("the solver thread answers each job in turn"),
+                },
+                "reload" | "r" => match load() {
+                    Ok(input) => {
+                        if let Err(err) = preprocess(ask(Job::Preprocess(input.clone()))?, &input, &mut out) {
+                            writeln!(out, "{}\nKeeping the previous input", err)?;
+                        }
+                    },
+                    Err(err) => writeln!(out, "Could not reload input: {}", err)?,
+                },
+                "help" | "h" | "?" => writeln!(out, "{}", HELP)?,
+                "quit" | "q" | "exit" => return Ok(()),
+                command => writeln!(out, "Unknown command {}, try help", command)?,
+            }
+            prompt(solver, &mut out)?;
+        }
+        Ok(())
+    })
+}
+
+/// Does each job against the session, keeping it if a new input is rejected or panics.
+fn solver_thread(solver: &dyn SolverDyn, jobs: Receiver<Job>, done: Sender<Done>) {
+    let mut session: Option<Box<dyn Session>> = None;
+    let caught = |payload: Box<dyn std::any::Any + Send>| panic_message(payload.as_ref());
+
+    for job in jobs {
+        let result = match job {
+            Job::Preprocess(input) => {
+                let (result, duration) = measure(|| panic::catch_unwind(AssertUnwindSafe(|| solver.session(&input))));
+                Done::Preprocessed(match result {
+                    Ok(Ok(preprocessed)) => {
+                        session = Some(preprocessed);
+                        Ok(duration)
+                    },
+                    Ok(Err(diagnostic)) => Err(diagnostic.to_string()),
+                    Err(payload) => Err(format!("Preprocessing panicked: {}", caught(payload))),
+                })
+            },
+            Job::Run(part) => {
+                let session = session.as_ref().expect("the input is preprocessed before any part runs");
+                let (answer, duration) = measure(|| panic::catch_unwind(AssertUnwindSafe(|| match part {
+                    Part::One => session.part_one(),
+                    Part::Two => session.part_two(),
+                })));
+                Done::Ran(answer.map(|answer| (answer, duration)).map_err(caught))
+            },
+            Job::Inspect => Done::Inspected(session.as_ref().map(|session| session.inspect()).unwrap_or_default()),
+        };
+        if done.send(result).is_err() {
+            return;
+        }
+    }
+}
+
+/// Reports how preprocessing went, returning the error if the input was rejected or panicked.
+fn preprocess(done: Done, input: &str, out: &mut impl Write) -> Result<(), Box<dyn Error>> {
+    match done {
+        Done::Preprocessed(Ok(duration)) => {
+            writeln!(out, "Preprocessed {} bytes in {}", input.len(), format_duration(duration))?;
+            Ok(())
+        },
+        Done::Preprocessed(Err(err)) => Err(err.into()),
+        _ => unreachable!("the solver thread answers each job in turn"),
+    }
+}
+
+/// Reports a part's answer, or its panic instead of ending the session.
+fn run_part(done: Done, out: &mut impl Write) -> Result<(), Box<dyn Error>> {
+    match done {
+        Done::Ran(Ok((answer, duration))) => writeln!(out, "{} ({})", answer, format_duration(duration))?,
+        Done::Ran(Err(_)) => writeln!(out, "Part panicked")?,
+        _ => unreachable!("the solver thread answers each job in turn"),
+    }
+    Ok(())
+}
+
+fn prompt(solver: &dyn SolverDyn, out: &mut impl Write) -> Result<(), Box<dyn Error>> {
+    write!(out, "{} day {}> ", solver.year(), solver.day())?;
+    out.flush()?;
+    Ok(())
+}
+
+
+#[cfg(all(test, feature = "year2025"))]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+    use crate::runner::find_solver;
+
+    fn run_commands(commands: &str, load: impl Fn() -> Result<String, Box<dyn Error>>) -> String {
+        let solver = find_solver("2025", "03").unwrap();
+        let mut out = vec![];
+        repl(solver, load, commands.as_bytes(), &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn run_parts_and_inspect() {
+        let out = run_commands("1\ninspect\nquit\n2\n", || Ok("987654321111111\n".to_string()));
+        assert!(out.starts_with("Preprocessed 16 bytes in "));
+        assert!(out.contains("2025 day 03> 98 ("));
+        assert!(out.contains("\"987654321111111\\n\""));
+        assert!(!out.contains("987654321111 ("));
+    }
+
+    #[test]
+    fn reload_reads_input_again() {
+        let loads = Cell::new(0);
+        let out = run_commands("reload\n1\n", || {
+            loads.set(loads.get() + 1);
+            Ok(if loads.get() == 1 { "11\n" } else { "19\n" }.to_string())
+        });
+        assert_eq!(loads.get(), 2);
+        assert!(out.contains("> 19 ("));
+    }
+
+    #[test]
+    fn reload_keeps_input_when_rejected() {
+        let solver = find_solver("2025", "07").unwrap();
+        let loads = Cell::new(0);
+        let load = || {
+            loads.set(loads.get() + 1);
+            Ok(if loads.get() == 1 { ".S.\n.^.\n" } else { ".S.\n.x.\n" }.to_string())
+        };
+        let mut out = vec![];
+        repl(solver, load, "reload\n1\n".as_bytes(), &mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains("error: Unexpected char 'x'"));
+        assert!(out.contains("Keeping the previous input\n2025 day 07> 1 ("));
+    }
+
+    #[test]
+    fn reload_keeps_input_when_preprocessing_panics() {
+        let solver = find_solver("2025", "02").unwrap();
+        let loads = Cell::new(0);
+        let load = || {
+            loads.set(loads.get() + 1);
+            Ok(if loads.get() == 1 { "11-22\n" } else { "11\n" }.to_string())
+        };
+        let mut out = vec![];
+        repl(solver, load, "reload\n1\n".as_bytes(), &mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains("Preprocessing panicked: each start of range"));
+        assert!(out.contains("Keeping the previous input\n2025 day 02> 33 ("));
+
+        let mut out = vec![];
+        let err = repl(solver, || Ok("11\n".to_string()), "1\n".as_bytes(), &mut out).unwrap_err();
+        assert!(err.to_string().starts_with("Preprocessing panicked"));
+    }
+
+    #[test]
+    fn deep_recursion_runs_on_solver_thread() {
+        // Far deeper than the stack of the thread running this test.
+        let mut input: String = (0..20_000).map(|i| format!("d{}: d{}\n", i, i + 1)).collect();
+        input.push_str("you: d0\nsvr: fft\nfft: dac\ndac: d0\nd20000: out\n");
+        let solver = find_solver("2025", "11").unwrap();
+        let mut out = vec![];
+        repl(solver, || Ok(input.clone()), "1\n2\n".as_bytes(), &mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains("2025 day 11> 1 ("));
+    }
+
+    #[test]
+    fn unknown_command() {
+        let out = run_commands("frobnicate\n", || Ok("11\n".to_string()));
+        assert!(out.contains("Unknown command frobnicate, try help"));
+    }
+}
@@ -0,0 +1,296 @@
+//!
+//! Summary reports for a year, as Markdown or as a self-contained HTML page.
+//!
+//! Reports are meant to be published, so they never include the answers themselves.
+//!
+
+use std::fmt::Write as _;
+use std::time::Duration;
+
+use crate::runner::{self, RunError, RunOptions};
+use crate::utils::answers::ExpectedAnswers;
+use crate::utils::inputs::{day_name, days_in_year, ProblemInputs};
+use crate::utils::performance::format_duration;
+use crate::utils::solver::{Part, Solution};
+
+#[derive(Debug, Clone)]
+pub enum DayOutcome {
+    NoSolver,
+    NoInput,
+    Failed(String),
+    Solved(Solution),
+}
+
+#[derive(Debug, Clone)]
+pub struct DaySummary {
+    pub day: String,
+    pub outcome: DayOutcome,
+    /// Parts with a verified answer in the answers store.
+    pub stars: usize,
+    /// Whether each part's answer matches the verified answer, where there is one.
+    pub checks: [Option<bool>; 2],
+}
+
+impl DaySummary {
+    pub fn solution(&self) -> Option<&Solution> {
+        match &self.outcome {
+            DayOutcome::Solved(solution) => Some(solution),
+            _ => None,
+        }
+    }
+
+    /// The slowest of preprocessing, part 1 and part 2.
+    pub fn slowest_phase(&self) -> Option<(&'static str, Duration)> {
+        let solution = self.solution()?;
+        phases(solution).into_iter().max_by_key(|&(_, duration)| duration)
+    }
+}
+
+/// Runs every solver for `year` which has an input with `options`, and summarises each day of the event.
+pub fn summarise_year(year: &str, inputs: &ProblemInputs, answers: &ExpectedAnswers, options: &RunOptions) -> Vec<DaySummary> {
+    (1..=days_in_year(year))
+        .map(|day| {
+            let day = day_name(day);
+            let stars = [Part::One, Part::Two]
+                .iter()
+                .filter(|&&part| answers.get_part(year, &day, part).is_some())
+                .count();
+
+            let outcome = match (runner::find_solver(year, &day), inputs.find(year, &day)) {
+                (None, _) => DayOutcome::NoSolver,
+                (_, None) => DayOutcome::NoInput,
+                (Some(_), Some(input)) => match input.get_text() {
+                    Err(err) => DayOutcome::Failed(err.to_string()),
+                    Ok(text) => match runner::run(year, &day, &text, options) {
+                        Ok(result) => DayOutcome::Solved(result.solution),
+                        Err(RunError::NoSolver { .. }) => DayOutcome::NoSolver,
+                        Err(err) => DayOutcome::Failed(err.to_string()),
+                    },
+                },
+            };
+
+            let checks = match &outcome {
+                DayOutcome::Solved(solution) => [Part::One, Part::Two]
+                    .map(|part| answers.check(year, &day, part, solution.get(part))),
+                _ => [None, None],
+            };
+
+            DaySummary { day, outcome, stars, checks }
+        })
+        .collect()
+}
+
+pub fn markdown(year: &str, days: &[DaySummary]) -> String {
+    let mut out = String::new();
+    writeln!(out, "# Advent of Code {}\n", year).unwrap();
+    writeln!(out, "| Day | Stars | Verified | Preprocess | Part 1 | Part 2 | Total | Slowest phase |").unwrap();
+    writeln!(out, "|-----|-------|----------|-----------:|-------:|-------:|------:|---------------|").unwrap();
+
+    for summary in days {
+        let timings = match &summary.outcome {
+            DayOutcome::Solved(solution) => {
+                let (phase, _) = summary.slowest_phase().expect("solved days have phases");
+                format!(
+                    "{} | {} | {} | {} | {}",
+                    format_duration(solution.preprocess_time),
+                    format_duration(solution.part_one_time),
+                    format_duration(solution.part_two_time),
+                    format_duration(solution.total_time()),
+                    phase
+                )
+            },
+            DayOutcome::NoSolver => "| | | | no solver".to_string(),
+            DayOutcome::NoInput => "| | | | no input".to_string(),
+            DayOutcome::Failed(_) => "| | | | failed".to_string(),
+        };
+        writeln!(out, "| {} | {} | {} | {} |", summary.day, stars(summary.stars), checks(&summary.checks), timings).unwrap();
+    }
+
+    let total_stars: usize = days.iter().map(|d| d.stars).sum();
+    let total_time: Duration = days.iter().filter_map(|d| d.solution()).map(Solution::total_time).sum();
+    writeln!(out, "\n{} of {} stars, solved in {}.", total_stars, days.len() * 2, format_duration(total_time)).unwrap();
+
+    let slowest = slowest_phases(days, 5);
+    if !slowest.is_empty() {
+        writeln!(out, "\n## Slowest phases\n").unwrap();
+        for (i, (day, phase, duration)) in slowest.iter().enumerate() {
+            writeln!(out, "{}. Day {} {} - {}", i + 1, day, phase, format_duration(*duration)).unwrap();
+        }
+    }
+    out
+}
+
+pub fn html(year: &str, days: &[DaySummary]) -> String {
+    let mut rows = String::new();
+    for summary in days {
+        let cells = match &summary.outcome {
+            DayOutcome::Solved(solution) => format!(
+                "<td>{}</td><td>{}</td><td>{}</td><td>{}</td>",
+                format_duration(solution.preprocess_time),
+                format_duration(solution.part_one_time),
+                format_duration(solution.part_two_time),
+                format_duration(solution.total_time())
+            ),
+            DayOutcome::NoSolver => "<td colspan=\"4\" class=\"missing\">no solver</td>".to_string(),
+            DayOutcome::NoInput => "<td colspan=\"4\" class=\"missing\">no input</td>".to_string(),
+            DayOutcome::Failed(message) => format!("<td colspan=\"4\" class=\"failed\">{}</td>", escape_html(message)),
+        };
+        writeln!(
+            rows,
+            "<tr><td>{}</td><td class=\"stars\">{}</td><td>{}</td>{}</tr>",
+            summary.day, stars(summary.stars), checks(&summary.checks), cells
+        ).unwrap();
+    }
+
+    let max_nanos = days.iter().filter_map(|d| d.solution()).map(|s| s.total_time().as_nanos()).max().unwrap_or(0);
+    let mut bars = String::new();
+    for summary in days {
+        if let Some(solution) = summary.solution() {
+            let total = solution.total_time();
+            writeln!(
+                bars,
+                "<div class=\"bar-row\"><span class=\"label\">Day {}</span><div class=\"bar\" style=\"width: {:.1}%\"></div><span class=\"time\">{}</span></div>",
+                summary.day,
+                bar_width(total.as_nanos(), max_nanos),
+                format_duration(total)
+            ).unwrap();
+        }
+    }
+
+    format!(r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<title>Advent of Code {year}</title>
+<style>
+body {{ font-family: sans-serif; background: #0f0f23; color: #ccc; margin: 2em; }}
+h1, h2 {{ color: #00cc00; }}
+table {{ border-collapse: collapse; }}
+td, th {{ padding: 0.3em 0.8em; border-bottom: 1px solid #333; text-align: right; }}
+.stars {{ color: #ffff66; text-align: left; }}
+.missing {{ color: #666; text-align: left; }}
+.failed {{ color: #ff6666; text-align: left; }}
+.bar-row {{ display: flex; align-items: center; margin: 0.2em 0; }}
+.label {{ width: 5em; }}
+.bar {{ height: 1em; background: #00cc00; margin-right: 0.5em; }}
+.time {{ color: #999; }}
+</style>
+</head>
+<body>
+<h1>Advent of Code {year}</h1>
+<table>
+<tr><th>Day</th><th>Stars</th><th>Verified</th><th>Preprocess</th><th>Part 1</th><th>Part 2</th><th>Total</th></tr>
+{rows}</table>
+<h2>Total time per day</h2>
+<p>Bars use a log scale.</p>
+{bars}</body>
+</html>
+"#)
+}
+
+fn phases(solution: &Solution) -> [(&'static str, Duration); 3] {
+    [
+        ("preprocessing", solution.preprocess_time),
+        ("part 1", solution.part_one_time),
+        ("part 2", solution.part_two_time),
+    ]
+}
+
+fn slowest_phases(days: &[DaySummary], count: usize) -> Vec<(&str, &'static str, Duration)> {
+    let mut all: Vec<_> = days
+        .iter()
+        .filter_map(|d| d.solution().map(|s| (d.day.as_str(), s)))
+        .flat_map(|(day, solution)| phases(solution).map(|(phase, duration)| (day, phase, duration)))
+        .collect();
+    all.sort_by_key(|&(_, _, duration)| std::cmp::Reverse(duration));
+    all.truncate(count);
+    all
+}
+
+fn stars(count: usize) -> String {
+    format!("{}{}", "★".repeat(count), "☆".repeat(2 - count.min(2)))
+}
+
+fn checks(checks: &[Option<bool>; 2]) -> String {
+    checks
+        .iter()
+        .map(|check| match check {
+            Some(true) => "✓",
+            Some(false) => "✗",
+            None => "–",
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Bar width as a percentage, on a log scale starting at 1µs so that fast days are still visible.
+fn bar_width(nanos: u128, max_nanos: u128) -> f64 {
+    let scale = |n: u128| ((n as f64) / 1e3).max(1.0).log10();
+    if scale(max_nanos) == 0.0 {
+        return 100.0;
+    }
+    (100.0 * scale(nanos) / scale(max_nanos)).max(1.0)
+}
+
+fn escape_html(s: &str) -> String {
+    s.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    pub(crate) fn solved(day: &str, stars: usize, checks: [Option<bool>; 2]) -> DaySummary {
+        DaySummary {
+            day: day.to_string(),
+            outcome: DayOutcome::Solved(Solution {
+                one: "secret-one".to_string(),
+                two: "secret-two".to_string(),
+                preprocess_time: Duration::from_micros(5),
+                part_one_time: Duration::from_millis(20),
+                part_two_time: Duration::from_millis(3),
+            }),
+            stars,
+            checks,
+        }
+    }
+
+    #[test]
+    fn markdown_rows() {
+        let days = vec![
+            solved("01", 2, [Some(true), Some(false)]),
+            DaySummary { day: "02".to_string(), outcome: DayOutcome::NoInput, stars: 0, checks: [None, None] },
+        ];
+        let out = markdown("2025", &days);
+        assert!(out.contains("| 01 | ★★ | ✓ ✗ | 5.00 µs | 20.0 ms | 3.00 ms | 23.0 ms | part 1 |"));
+        assert!(out.contains("| 02 | ☆☆ | – – | | | | | no input |"));
+        assert!(out.contains("2 of 4 stars"));
+        assert!(out.contains("1. Day 01 part 1 - 20.0 ms"));
+    }
+
+    #[test]
+    fn reports_exclude_answers() {
+        let days = vec![solved("01", 2, [Some(true), Some(true)])];
+        assert!(!markdown("2025", &days).contains("secret"));
+        assert!(!html("2025", &days).contains("secret"));
+    }
+
+    #[test]
+    fn html_escapes_errors() {
+        let days = vec![DaySummary {
+            day: "01".to_string(),
+            outcome: DayOutcome::Failed("<oops>".to_string()),
+            stars: 0,
+            checks: [None, None],
+        }];
+        assert!(html("2025", &days).contains("&lt;oops&gt;"));
+    }
+
+    #[test]
+    fn bar_width_log_scale() {
+        assert_eq!(bar_width(1_000_000, 1_000_000), 100.0);
+        assert_eq!(bar_width(1_000, 1_000_000_000), 1.0);
+        assert_eq!(bar_width(1_000_000, 1_000_000_000), 50.0);
+    }
+}
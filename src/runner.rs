@@ -0,0 +1,315 @@
+//!
+//! Looking up solvers and running them on an input.
+//!
+
+use std::error::Error;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use crate::isolation;
+use crate::log;
+use crate::utils::alloc::allocation_count;
+use crate::utils::log::{self as logging, Level};
+use crate::utils::parse::Diagnostic;
+use crate::utils::solver::{Solution, SolverDyn};
+use crate::utils::trace::{self, Event};
+#[cfg(feature = "year2025")]
+use crate::year_2025;
+
+/// Stack size for solver threads when none is given. Deeply recursive solvers need far more
+/// than the 2 MiB Rust gives spawned threads, and the memory is only committed as it is used.
+pub const DEFAULT_STACK_SIZE: usize = 256 * 1024 * 1024;
+
+#[derive(Debug, Clone, Default)]
+pub struct RunOptions {
+    /// Print the solver's banner, timings and answers as it runs.
+    pub print: bool,
+    /// Give up on the solver if it has not finished within this time. Unless it is isolated,
+    /// the solver keeps running on its own thread in the background, as threads cannot be
+    /// cancelled.
+    pub timeout: Option<Duration>,
+    /// Capture trace events emitted by the solver.
+    pub trace: bool,
+    /// Stack size in bytes for the thread the solver runs on, `DEFAULT_STACK_SIZE` if not set.
+    pub stack_size: Option<usize>,
+    /// Run the solver in a child process, so that a stack overflow, which aborts the process,
+    /// is reported as `RunError::StackOverflow` instead.
+    pub isolate: bool,
+    /// The binary an isolated run executes, the current one if not set. It must call
+    /// `run_child` when given `CHILD_ARG` as its first argument.
+    pub child_binary: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RunResult {
+    pub year: String,
+    pub day: String,
+    pub solution: Solution,
+    /// Allocations made while solving, if the binary counts them.
+    pub allocations: Option<u64>,
+    /// Trace events emitted by the solver, if tracing was requested.
+    pub events: Vec<Event>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RunError {
+    NoSolver { year: String, day: String },
+    Panicked { year: String, day: String, message: String },
+    TimedOut { year: String, day: String, timeout: Duration },
+    /// The solver rejected its input, pointing at where the problem is.
+    InvalidInput { year: String, day: String, diagnostic: Box<Diagnostic> },
+    /// The isolated solver's process overflowed its stack.
+    StackOverflow { year: String, day: String },
+}
+
+impl fmt::Display for RunError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RunError::NoSolver { year, day } => write!(f, "No solver found for year {} day {}", year, day),
+            RunError::Panicked { year, day, message } => write!(f, "Solver for year {} day {} panicked: {}", year, day, message),
+            RunError::TimedOut { year, day, timeout } => write!(f, "Solver for year {} day {} did not finish within {:?}", year, day, timeout),
+            RunError::InvalidInput { diagnostic, .. } => write!(f, "{}", diagnostic),
+            RunError::StackOverflow { year, day } => write!(f, "Solver for year {} day {} overflowed its stack", year, day),
+        }
+    }
+}
+
+impl RunError {
+    pub fn year(&self) -> &str {
+        match self {
+            RunError::NoSolver { year, .. }
+            | RunError::Panicked { year, .. }
+            | RunError::TimedOut { year, .. }
+            | RunError::InvalidInput { year, .. }
+            | RunError::StackOverflow { year, .. } => year,
+        }
+    }
+
+    pub fn day(&self) -> &str {
+        match self {
+            RunError::NoSolver { day, .. }
+            | RunError::Panicked { day, .. }
+            | RunError::TimedOut { day, .. }
+            | RunError::InvalidInput { day, .. }
+            | RunError::StackOverflow { day, .. } => day,
+        }
+    }
+}
+
+impl Error for RunError {}
+
+/// All compiled in solvers, ordered by year then day. Each year is behind a cargo feature.
+pub fn solvers() -> Vec<&'static dyn SolverDyn> {
+    #[allow(unused_mut)]
+    let mut solvers: Vec<&'static dyn SolverDyn> = vec![];
+    #[cfg(feature = "year2025")]
+    solvers.extend_from_slice(year_2025::SOLVERS);
+    solvers.sort_by(|a, b| (a.year(), a.day()).cmp(&(b.year(), b.day())));
+    solvers
+}
+
+pub fn find_solver(year: &str, day: &str) -> Option<&'static dyn SolverDyn> {
+    solvers().into_iter().find(|s| s.year() == year && s.day() == day)
+}
+
+/// Runs the solver for `year` and `day` on `input`.
+///
+/// A panicking solver is reported as an error rather than unwinding into the caller, as is
+/// an input the solver rejects with a diagnostic. With `RunOptions::isolate` the solver runs
+/// in a child process, so even a stack overflow is reported as an error.
+///
+/// # Examples
+///
+/// ```
+/// use advent::{run, RunOptions};
+///
+/// # #[cfg(feature = "year2025")] {
+/// let input = "[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}";
+/// let result = run("2025", "10", input, &RunOptions::default()).unwrap();
+/// assert_eq!(result.solution.one, "2");
+/// assert_eq!(result.solution.two, "10");
+/// # }
+/// ```
+pub fn run(year: &str, day: &str, input: &str, options: &RunOptions) -> Result<RunResult, RunError> {
+    let Some(solver) = find_solver(year, day) else {
+        return Err(RunError::NoSolver { year: year.to_string(), day: day.to_string() });
+    };
+    if options.isolate {
+        return isolation::run(year, day, input, options);
+    }
+
+    // Solvers always run on their own thread, named after the solver, so the stack size can be
+    // set. Rust aborts the whole process on a stack overflow, reporting the thread's name,
+    // which is why callers that must survive one isolate the run in a child process.
+    let (sender, receiver) = mpsc::channel();
+    let input = input.to_string();
+    let (print, trace) = (options.print, options.trace);
+    thread::Builder::new()
+        .name(format!("{} day {}", year, day))
+        .stack_size(options.stack_size.unwrap_or(DEFAULT_STACK_SIZE))
+        .spawn(move || {
+            // The receiver is gone if the run already timed out, so there is nobody to tell.
+            let _ = sender.send(run_solver(solver, &input, print, trace));
+        })
+        .expect("failed to spawn solver thread");
+
+    let exited = || RunError::Panicked {
+        year: year.to_string(),
+        day: day.to_string(),
+        message: "solver thread exited without a result".to_string(),
+    };
+    let solution = match options.timeout {
+        None => receiver.recv().unwrap_or_else(|_| Err(exited())),
+        Some(timeout) => match receiver.recv_timeout(timeout) {
+            Ok(solution) => solution,
+            Err(mpsc::RecvTimeoutError::Timeout) => {
+                return Err(RunError::TimedOut { year: year.to_string(), day: day.to_string(), timeout });
+            },
+            Err(mpsc::RecvTimeoutError::Disconnected) => Err(exited()),
+        },
+    };
+
+    let (solution, allocations, events) = solution?;
+    Ok(RunResult { year: year.to_string(), day: day.to_string(), solution, allocations, events })
+}
+
+fn run_solver(solver: &dyn SolverDyn, input: &str, print: bool, trace: bool) -> Result<(Solution, Option<u64>, Vec<Event>), RunError> {
+    let (year, day) = (solver.year().to_string(), solver.day().to_string());
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        log!(Level::Trace, "Running {} day {} on {} bytes of input", solver.year(), solver.day(), input.len());
+        let start_allocations = allocation_count();
+        let (solution, events) = if trace {
+            trace::capture(|| solver.run(input))
+        } else {
+            (solver.run(input), vec![])
+        };
+        let allocations = allocation_count().zip(start_allocations).map(|(end, start)| end - start);
+        if let (true, Ok(solution)) = (print, &solution) {
+            print_solution(solver, solution, allocations);
+        }
+        (solution, allocations, events)
+    }));
+
+    match result {
+        Ok((Ok(solution), allocations, events)) => Ok((solution, allocations, events)),
+        Ok((Err(diagnostic), _, _)) => Err(RunError::InvalidInput { year, day, diagnostic }),
+        Err(payload) => Err(RunError::Panicked { year, day, message: panic_message(payload.as_ref()) }),
+    }
+}
+
+fn print_solution(solver: &dyn SolverDyn, solution: &Solution, allocations: Option<u64>) {
+    log!(Level::Normal, "\n- - - - - {} day {} - - - - -", solver.year(), solver.day());
+    log!(Level::Normal, "Preprocessing executed in {:?}", solution.preprocess_time);
+    log!(Level::Normal, "Part 1 executed in {:?}", solution.part_one_time);
+    logging::answer(&solution.one);
+    log!(Level::Normal, "Part 2 executed in {:?}", solution.part_two_time);
+    logging::answer(&solution.two);
+    if let Some(allocations) = allocations {
+        log!(Level::Verbose, "Allocations: {}", allocations);
+    }
+    log!(Level::Normal, "- - - - - - - - - - - - - - - -");
+}
+
+pub(crate) fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solvers_are_sorted() {
+        let solvers = solvers();
+        assert!(solvers.windows(2).all(|w| (w[0].year(), w[0].day()) < (w[1].year(), w[1].day())));
+    }
+
+    #[test]
+    #[cfg(feature = "year2025")]
+    fn find_solver_exists() {
+        let solver = find_solver("2025", "07").unwrap();
+        assert_eq!((solver.year(), solver.day()), ("2025", "07"));
+    }
+
+    #[test]
+    fn run_no_solver() {
+        let result = run("1999", "01", "", &RunOptions::default());
+        assert_eq!(result, Err(RunError::NoSolver { year: "1999".to_string(), day: "01".to_string() }));
+    }
+
+    #[test]
+    #[cfg(feature = "year2025")]
+    fn run_panicking_solver() {
+        let result = run("2025", "02", "11\n", &RunOptions::default());
+        assert!(matches!(result, Err(RunError::Panicked { .. })));
+    }
+
+    #[test]
+    #[cfg(feature = "year2025")]
+    fn run_missing_device() {
+        let Err(RunError::InvalidInput { diagnostic, .. }) = run("2025", "11", "aaa: out\n", &RunOptions::default()) else {
+            panic!("expected a diagnostic");
+        };
+        assert_eq!(diagnostic.message, "No device named you");
+    }
+
+    #[test]
+    #[cfg(feature = "year2025")]
+    fn run_invalid_input() {
+        let Err(RunError::InvalidInput { diagnostic, .. }) = run("2025", "07", "..S..\n..x..\n", &RunOptions::default()) else {
+            panic!("expected a diagnostic");
+        };
+        assert_eq!((diagnostic.line, diagnostic.column), (2, 3));
+        assert_eq!((diagnostic.year.as_deref(), diagnostic.day.as_deref()), (Some("2025"), Some("07")));
+    }
+
+    #[test]
+    #[cfg(feature = "year2025")]
+    fn run_with_timeout() {
+        let options = RunOptions { timeout: Some(Duration::from_secs(10)), ..RunOptions::default() };
+        let result = run("2025", "03", "987654321111111\n", &options).unwrap();
+        assert_eq!(result.solution.one, "98");
+    }
+
+    #[test]
+    #[cfg(feature = "year2025")]
+    fn run_with_trace() {
+        let options = RunOptions { trace: true, ..RunOptions::default() };
+        let input = "[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}";
+        let result = run("2025", "10", input, &options).unwrap();
+        assert!(result.events.iter().any(|e| e.name == "pivot"));
+
+        let result = run("2025", "10", input, &RunOptions::default()).unwrap();
+        assert!(result.events.is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "year2025")]
+    fn run_deep_recursion_with_large_stack() {
+        // Day 11 recurses once per device along a path, so a long chain needs a deep stack.
+        let mut input: String = (0..20_000).map(|i| format!("d{}: d{}\n", i, i + 1)).collect();
+        input.push_str("you: d0\nsvr: fft\nfft: dac\ndac: d0\nd20000: out\n");
+        let options = RunOptions { stack_size: Some(DEFAULT_STACK_SIZE), ..RunOptions::default() };
+        let result = run("2025", "11", &input, &options).unwrap();
+        assert_eq!((result.solution.one.as_str(), result.solution.two.as_str()), ("1", "1"));
+    }
+
+    #[test]
+    #[cfg(feature = "year2025")]
+    fn run_panicking_solver_with_timeout() {
+        let options = RunOptions { timeout: Some(Duration::from_secs(10)), ..RunOptions::default() };
+        let result = run("2025", "02", "11\n", &options);
+        assert!(matches!(result, Err(RunError::Panicked { .. })));
+    }
+}
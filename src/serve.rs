@@ -0,0 +1,279 @@
+//!
+//! A small HTTP service for running solvers, returning JSON.
+//!
+//! - `GET /solvers` lists every solver.
+//! - `POST /run/yyyy/dd` runs a solver with the request body as its input.
+//!
+
+use std::error::Error;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::thread;
+use std::time::Duration;
+
+use crate::runner::{self, RunError, RunOptions, RunResult};
+use crate::utils::json::Json;
+
+const MAX_BODY_BYTES: usize = 16 * 1024 * 1024;
+/// The most the request line and headers together may take.
+const MAX_HEADER_BYTES: u64 = 64 * 1024;
+
+#[derive(Debug, Clone)]
+pub struct ServeOptions {
+    pub addr: String,
+    /// How long a single run may take before the request fails, and how long a read or
+    /// write on the connection may wait.
+    pub timeout: Duration,
+    /// Run each request's solver in a child process, so a stack overflow fails only that request.
+    pub isolate: bool,
+}
+
+impl Default for ServeOptions {
+    fn default() -> Self {
+        ServeOptions { addr: "127.0.0.1:7878".to_string(), timeout: Duration::from_secs(30), isolate: false }
+    }
+}
+
+#[derive(Debug)]
+struct Request {
+    method: String,
+    path: String,
+    body: String,
+}
+
+#[derive(Debug)]
+struct Response {
+    status: u16,
+    body: Json,
+}
+
+impl Response {
+    fn error(status: u16, message: &str) -> Response {
+        Response { status, body: Json::object([("error", Json::from(message))]) }
+    }
+}
+
+/// Listens on `options.addr`, handling each connection on its own thread.
+pub fn serve(options: &ServeOptions) -> Result<(), Box<dyn Error>> {
+    let listener = TcpListener::bind(&options.addr)?;
+    println!("Listening on http://{}", listener.local_addr()?);
+
+    for stream in listener.incoming() {
+        let stream = stream?;
+        let options = options.clone();
+        thread::spawn(move || {
+            if let Err(err) = handle_connection(stream, &options) {
+                eprintln!("Error handling request: {}", err);
+            }
+        });
+    }
+    Ok(())
+}
+
+fn handle_connection(mut stream: TcpStream, options: &ServeOptions) -> io::Result<()> {
+    // A client which stops sending or reading would otherwise hold its thread forever.
+    stream.set_read_timeout(Some(options.timeout))?;
+    stream.set_write_timeout(Some(options.timeout))?;
+
+    let response = match read_request(&mut stream) {
+        Ok(request) => handle(&request, options),
+        Err(err) => Response::error(400, &err.to_string()),
+    };
+
+    let body = response.body.to_string();
+    write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.status,
+        reason(response.status),
+        body.len(),
+        body
+    )?;
+    stream.flush()
+}
+
+fn read_request(stream: impl Read) -> io::Result<Request> {
+    // Headers are read through a limit, which is raised to the body's length once known.
+    let mut reader = BufReader::new(stream.take(MAX_HEADER_BYTES));
+    let invalid = |message: &str| io::Error::new(io::ErrorKind::InvalidData, message.to_string());
+    let mut read_line = |line: &mut String| -> io::Result<usize> {
+        let read = reader.read_line(line)?;
+        if read > 0 && !line.ends_with('\n') && reader.get_ref().limit() == 0 {
+            return Err(invalid("Request headers are too large"));
+        }
+        Ok(read)
+    };
+
+    let mut request_line = String::new();
+    read_line(&mut request_line)?;
+    let mut parts = request_line.split_ascii_whitespace();
+    let (Some(method), Some(path)) = (parts.next(), parts.next()) else {
+        return Err(invalid("Malformed request line"));
+    };
+
+    let mut content_length = 0;
+    loop {
+        let mut header = String::new();
+        if read_line(&mut header)? == 0 || header.trim().is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':')
+            && name.trim().eq_ignore_ascii_case("content-length") {
+            content_length = value.trim().parse().map_err(|_| invalid("Invalid Content-Length"))?;
+        }
+    }
+
+    if content_length > MAX_BODY_BYTES {
+        return Err(invalid("Request body is too large"));
+    }
+    reader.get_mut().set_limit(content_length as u64);
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body)?;
+
+    Ok(Request {
+        method: method.to_string(),
+        path: path.to_string(),
+        body: String::from_utf8(body).map_err(|_| invalid("Request body should be UTF-8"))?,
+    })
+}
+
+fn handle(request: &Request, options: &ServeOptions) -> Response {
+    let segments: Vec<&str> = request.path.trim_matches('/').split('/').collect();
+
+    match (request.method.as_str(), segments.as_slice()) {
+        ("GET", ["solvers"]) => {
+            let solvers = runner::solvers()
+                .into_iter()
+                .map(|s| Json::object([("year", Json::from(s.year())), ("day", Json::from(s.day()))]))
+                .collect();
+            Response { status: 200, body: Json::Array(solvers) }
+        },
+        ("POST", ["run", year, day]) => {
+            let run_options = RunOptions { timeout: Some(options.timeout), isolate: options.isolate, ..RunOptions::default() };
+            match runner::run(year, day, &request.body, &run_options) {
+                Ok(result) => Response { status: 200, body: result_json(&result) },
+                Err(err) => Response { status: error_status(&err), body: error_json(year, day, &err) },
+            }
+        },
+        (_, ["solvers"]) | (_, ["run", _, _]) => Response::error(405, "Method not allowed"),
+        _ => Response::error(404, "Not found"),
+    }
+}
+
+fn result_json(RunResult { year, day, solution, .. }: &RunResult) -> Json {
+    Json::object([
+        ("year", Json::from(year.as_str())),
+        ("day", Json::from(day.as_str())),
+        ("ok", Json::from(true)),
+        ("answers", Json::object([
+            ("part_one", Json::from(solution.one.as_str())),
+            ("part_two", Json::from(solution.two.as_str())),
+        ])),
+        ("timings_ns", Json::object([
+            ("preprocess", Json::Int(solution.preprocess_time.as_nanos() as i128)),
+            ("part_one", Json::Int(solution.part_one_time.as_nanos() as i128)),
+            ("part_two", Json::Int(solution.part_two_time.as_nanos() as i128)),
+            ("total", Json::Int(solution.total_time().as_nanos() as i128)),
+        ])),
+    ])
+}
+
+fn error_json(year: &str, day: &str, err: &RunError) -> Json {
+    let kind = match err {
+        RunError::NoSolver { .. } => "no_solver",
+        RunError::Panicked { .. } => "panicked",
+        RunError::TimedOut { .. } => "timed_out",
+        RunError::InvalidInput { .. } => "invalid_input",
+        RunError::StackOverflow { .. } => "stack_overflow",
+    };
+    Json::object([
+        ("year", Json::from(year)),
+        ("day", Json::from(day)),
+        ("ok", Json::from(false)),
+        ("error", Json::object([
+            ("kind", Json::from(kind)),
+            ("message", Json::from(err.to_string())),
+        ])),
+    ])
+}
+
+fn error_status(err: &RunError) -> u16 {
+    match err {
+        RunError::NoSolver { .. } => 404,
+        RunError::Panicked { .. } | RunError::StackOverflow { .. } => 500,
+        RunError::TimedOut { .. } => 504,
+        RunError::InvalidInput { .. } => 422,
+    }
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        422 => "Unprocessable Content",
+        500 => "Internal Server Error",
+        504 => "Gateway Timeout",
+        _ => "",
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(method: &str, path: &str, body: &str) -> Request {
+        Request { method: method.to_string(), path: path.to_string(), body: body.to_string() }
+    }
+
+    #[test]
+    #[cfg(feature = "year2025")]
+    fn list_solvers() {
+        let response = handle(&request("GET", "/solvers", ""), &ServeOptions::default());
+        assert_eq!(response.status, 200);
+        assert!(response.body.to_string().contains(r#"{"year":"2025","day":"01"}"#));
+    }
+
+    #[test]
+    #[cfg(feature = "year2025")]
+    fn run_solver() {
+        let input = "[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}";
+        let response = handle(&request("POST", "/run/2025/10", input), &ServeOptions::default());
+        assert_eq!(response.status, 200);
+        assert!(response.body.to_string().contains(r#""answers":{"part_one":"2","part_two":"10"}"#));
+    }
+
+    #[test]
+    fn run_unknown_solver() {
+        let response = handle(&request("POST", "/run/1999/01", ""), &ServeOptions::default());
+        assert_eq!(response.status, 404);
+        assert!(response.body.to_string().contains(r#""kind":"no_solver""#));
+    }
+
+    #[test]
+    fn wrong_method() {
+        let response = handle(&request("GET", "/run/2025/01", ""), &ServeOptions::default());
+        assert_eq!(response.status, 405);
+    }
+
+    #[test]
+    fn read_request_with_body() {
+        let request = read_request("POST /run/2025/03 HTTP/1.1\r\nContent-Length: 4\r\n\r\n11\n\n".as_bytes()).unwrap();
+        assert_eq!((request.method.as_str(), request.path.as_str(), request.body.as_str()), ("POST", "/run/2025/03", "11\n\n"));
+    }
+
+    #[test]
+    fn read_request_limits_headers() {
+        let header = format!("GET /solvers HTTP/1.1\r\nX-Long: {}\r\n\r\n", "a".repeat(MAX_HEADER_BYTES as usize));
+        let err = read_request(header.as_bytes()).unwrap_err();
+        assert_eq!(err.to_string(), "Request headers are too large");
+    }
+
+    #[test]
+    fn unknown_path() {
+        let response = handle(&request("GET", "/", ""), &ServeOptions::default());
+        assert_eq!(response.status, 404);
+    }
+}
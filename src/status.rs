@@ -0,0 +1,134 @@
+//!
+//! Cross-checks solvers, inputs, example tests and verified answers, to show which
+//! days are complete and which are missing something.
+//!
+//! Nothing is run, so this is quick. Which solvers have tests is worked out by `build.rs`
+//! when the binary is built.
+//!
+
+use std::collections::BTreeSet;
+use std::fmt::Write as _;
+
+use crate::runner;
+use crate::utils::answers::ExpectedAnswers;
+use crate::utils::inputs::{day_name, days_in_year, ProblemInputs};
+use crate::utils::solver::Part;
+
+include!(concat!(env!("OUT_DIR"), "/solver_tests.rs"));
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DayStatus {
+    pub day: String,
+    pub solver: bool,
+    pub input: bool,
+    /// Whether the solver's source contains tests.
+    pub tests: bool,
+    /// Whether each part has a verified answer in the answers store.
+    pub verified: [bool; 2],
+}
+
+/// Whether the source for a year and day's solver contains tests.
+pub fn has_tests(year: &str, day: &str) -> bool {
+    SOLVER_TESTS.contains(&(year, day))
+}
+
+/// Every year with a solver, input or verified answer.
+pub fn years(inputs: &ProblemInputs, answers: &ExpectedAnswers) -> Vec<String> {
+    let mut years: BTreeSet<&str> = runner::solvers().into_iter().map(|s| s.year()).collect();
+    years.extend(inputs.years());
+    years.extend(answers.years());
+    years.into_iter().map(String::from).collect()
+}
+
+/// Checks each day of `year` against the solvers, inputs and answers store.
+pub fn year_status(year: &str, inputs: &ProblemInputs, answers: &ExpectedAnswers) -> Vec<DayStatus> {
+    (1..=days_in_year(year))
+        .map(|day| {
+            let day = day_name(day);
+            DayStatus {
+                solver: runner::find_solver(year, &day).is_some(),
+                input: inputs.find(year, &day).is_some(),
+                tests: has_tests(year, &day),
+                verified: [Part::One, Part::Two].map(|part| answers.get_part(year, &day, part).is_some()),
+                day,
+            }
+        })
+        .collect()
+}
+
+/// Warnings for inputs and answers with no solver, and solvers with no input.
+pub fn orphans(inputs: &ProblemInputs, answers: &ExpectedAnswers) -> Vec<String> {
+    let mut warnings = vec![];
+
+    for year in years(inputs, answers) {
+        for input in inputs.get(Some(&year), None) {
+            if runner::find_solver(&year, &input.day).is_none() {
+                warnings.push(format!("Input for year {} day {} has no solver", year, input.day));
+            }
+        }
+        for day in answers.days(&year) {
+            if runner::find_solver(&year, day).is_none() {
+                warnings.push(format!("Answers for year {} day {} have no solver", year, day));
+            }
+        }
+    }
+    for solver in runner::solvers() {
+        if inputs.find(solver.year(), solver.day()).is_none() {
+            warnings.push(format!("Solver for year {} day {} has no input", solver.year(), solver.day()));
+        }
+    }
+    warnings
+}
+
+pub fn render(year: &str, days: &[DayStatus]) -> String {
+    let mut out = String::new();
+    writeln!(out, "{}     Solver  Input  Tests  Part 1  Part 2", year).unwrap();
+    for status in days {
+        writeln!(
+            out,
+            "  Day {}   {}      {}      {}      {}       {}",
+            status.day,
+            mark(status.solver),
+            mark(status.input),
+            mark(status.tests),
+            mark(status.verified[0]),
+            mark(status.verified[1])
+        ).unwrap();
+    }
+    out
+}
+
+fn mark(value: bool) -> &'static str {
+    if value { "✓" } else { "✗" }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_marks() {
+        let days = vec![DayStatus {
+            day: "01".to_string(),
+            solver: true,
+            input: false,
+            tests: true,
+            verified: [true, false],
+        }];
+        let out = render("2025", &days);
+        assert!(out.contains("  Day 01   ✓      ✗      ✓      ✓       ✗\n"));
+    }
+
+    #[test]
+    fn tests_found_at_build_time() {
+        // Every day the build found tests for is a solver's day, and nothing else has tests.
+        for &(year, day) in SOLVER_TESTS {
+            assert!(has_tests(year, day));
+            assert!(day.len() == 2 && day.bytes().all(|b| b.is_ascii_digit()), "{} day {}", year, day);
+            #[cfg(feature = "year2025")]
+            assert!(runner::find_solver(year, day).is_some(), "{} day {} has tests but no solver", year, day);
+        }
+        assert!(!has_tests("1999", "01"));
+    }
+}
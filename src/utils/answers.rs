@@ -0,0 +1,160 @@
+//!
+//! Storage for answers which have been verified as correct.
+//!
+//! Answers are stored alongside the inputs at `/answers/yyyy/dd.txt`, with the
+//! part 1 answer on the first line and the part 2 answer on the second. An empty
+//! line means that part has not been verified yet.
+//!
+
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+
+use crate::utils::solver::Part;
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ExpectedAnswer {
+    pub one: Option<String>,
+    pub two: Option<String>,
+}
+
+impl ExpectedAnswer {
+    pub fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.one.as_deref(),
+            Part::Two => self.two.as_deref(),
+        }
+    }
+
+    fn set(&mut self, part: Part, answer: &str) {
+        match part {
+            Part::One => self.one = Some(answer.to_string()),
+            Part::Two => self.two = Some(answer.to_string()),
+        }
+    }
+
+    fn parse(text: &str) -> ExpectedAnswer {
+        let mut lines = text.lines().map(str::trim);
+        let mut next_answer = || lines.next().filter(|l| !l.is_empty()).map(String::from);
+        let one = next_answer();
+        let two = next_answer();
+        ExpectedAnswer { one, two }
+    }
+
+    fn to_text(&self) -> String {
+        format!(
+            "{}\n{}\n",
+            self.one.as_deref().unwrap_or(""),
+            self.two.as_deref().unwrap_or("")
+        )
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ExpectedAnswers {
+    base_path: String,
+    answers_map: BTreeMap<String, BTreeMap<String, ExpectedAnswer>>,
+}
+
+impl ExpectedAnswers {
+
+    /// Loads all answers under `path`. A missing directory is treated as an empty store.
+    pub fn load_from_path(path: &str) -> Result<ExpectedAnswers, Box<dyn Error>> {
+        let mut answers_map = BTreeMap::new();
+
+        if Path::new(path).is_dir() {
+            for entry in fs::read_dir(path)? {
+                let entry = entry?;
+                if entry.file_type()?.is_dir() {
+                    let year = String::from(entry.file_name().to_string_lossy());
+                    let year_answers_map = Self::load_year(path, &year)?;
+                    answers_map.insert(year, year_answers_map);
+                }
+            }
+        }
+
+        Ok(ExpectedAnswers { base_path: path.to_string(), answers_map })
+    }
+
+    pub fn years(&self) -> impl Iterator<Item = &str> {
+        self.answers_map.keys().map(String::as_str)
+    }
+
+    pub fn days(&self, year: &str) -> impl Iterator<Item = &str> {
+        self.answers_map.get(year).into_iter().flat_map(|days| days.keys().map(String::as_str))
+    }
+
+    pub fn get(&self, year: &str, day: &str) -> Option<&ExpectedAnswer> {
+        self.answers_map.get(year)?.get(day)
+    }
+
+    pub fn get_part(&self, year: &str, day: &str, part: Part) -> Option<&str> {
+        self.get(year, day)?.get(part)
+    }
+
+    /// Compares `answer` with the verified answer, or `None` if there isn't one yet.
+    pub fn check(&self, year: &str, day: &str, part: Part, answer: &str) -> Option<bool> {
+        self.get_part(year, day, part).map(|expected| expected == answer)
+    }
+
+    /// Records `answer` as the verified answer for a part and writes it to disk.
+    pub fn set(&mut self, year: &str, day: &str, part: Part, answer: &str) -> Result<(), Box<dyn Error>> {
+        let expected = self.answers_map
+            .entry(year.to_string())
+            .or_default()
+            .entry(day.to_string())
+            .or_default();
+        expected.set(part, answer);
+
+        fs::create_dir_all(format!("{}/{}", self.base_path, year))?;
+        fs::write(format!("{}/{}/{}.txt", self.base_path, year, day), expected.to_text())?;
+        Ok(())
+    }
+
+    fn load_year(path: &str, year: &str) -> Result<BTreeMap<String, ExpectedAnswer>, Box<dyn Error>> {
+        let mut answers = BTreeMap::new();
+
+        for entry in fs::read_dir(format!("{path}/{year}"))? {
+            let entry = entry?;
+            if entry.file_type()?.is_file() {
+                let day = String::from(entry.file_name().to_string_lossy());
+                let day = day.split('.').next().expect("split results in at least 1 string");
+                let text = fs::read_to_string(entry.path())?;
+                answers.insert(day.to_string(), ExpectedAnswer::parse(&text));
+            }
+        }
+        Ok(answers)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_both_parts() {
+        let answer = ExpectedAnswer::parse("123\nabc\n");
+        assert_eq!(answer.get(Part::One), Some("123"));
+        assert_eq!(answer.get(Part::Two), Some("abc"));
+    }
+
+    #[test]
+    fn parse_missing_part_one() {
+        let answer = ExpectedAnswer::parse("\n456\n");
+        assert_eq!(answer.get(Part::One), None);
+        assert_eq!(answer.get(Part::Two), Some("456"));
+    }
+
+    #[test]
+    fn parse_empty() {
+        assert_eq!(ExpectedAnswer::parse(""), ExpectedAnswer::default());
+    }
+
+    #[test]
+    fn to_text_round_trip() {
+        let answer = ExpectedAnswer { one: None, two: Some("7".to_string()) };
+        assert_eq!(ExpectedAnswer::parse(&answer.to_text()), answer);
+    }
+}
@@ -1,4 +1,10 @@
+use crate::utils::log::{self, Level};
+
+/// Prints a grid, only when running at the verbose level or above.
 pub fn print_vec_2d<T: std::fmt::Display>(matrix: &Vec<Vec<T>>) {
+    if !log::enabled(Level::Verbose) {
+        return;
+    }
     for row in matrix {
         for val in row {
             print!("{:} ", val);
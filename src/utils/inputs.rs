@@ -2,15 +2,25 @@ use std::collections::BTreeMap;
 use std::fs;
 use std::error::Error;
 
+#[cfg(feature = "embedded-inputs")]
+mod embedded {
+    include!(concat!(env!("OUT_DIR"), "/embedded_inputs.rs"));
+}
+
 #[derive(Debug, Clone)]
 pub struct ProblemInput {
     base_path: String,
     pub year: String,
     pub day: String,
+    /// The input's text, when it was embedded in the binary rather than read from disk.
+    embedded: Option<&'static str>,
 }
 
 impl ProblemInput {
     pub fn get_text(&self) -> Result<String, Box<dyn Error>> {
+        if let Some(text) = self.embedded {
+            return Ok(text.to_string());
+        }
         let text = fs::read_to_string(format!("{}/{}/{}.txt", self.base_path, self.year, self.day))?;
         Ok(text)
     }
@@ -23,6 +33,27 @@ pub struct ProblemInputs {
 
 impl ProblemInputs {
 
+    /// Loads the inputs embedded at build time with the `embedded-inputs` feature, or
+    /// otherwise those in `./inputs`.
+    pub fn load() -> Result<ProblemInputs, Box<dyn Error>> {
+        #[cfg(feature = "embedded-inputs")]
+        return Ok(Self::embedded());
+
+        #[cfg(not(feature = "embedded-inputs"))]
+        Self::load_from_path("./inputs")
+    }
+
+    /// The inputs found in `inputs/` when the binary was built.
+    #[cfg(feature = "embedded-inputs")]
+    pub fn embedded() -> ProblemInputs {
+        let mut inputs_map: BTreeMap<String, BTreeMap<String, ProblemInput>> = BTreeMap::new();
+        for &(year, day, text) in embedded::EMBEDDED_INPUTS {
+            let input = ProblemInput { base_path: String::new(), year: year.to_string(), day: day.to_string(), embedded: Some(text) };
+            inputs_map.entry(year.to_string()).or_default().insert(day.to_string(), input);
+        }
+        ProblemInputs { inputs_map }
+    }
+
     pub fn load_from_path(path: &str) -> Result<ProblemInputs, Box<dyn Error>> {
         let mut inputs_map = BTreeMap::new();
 
@@ -60,6 +91,15 @@ impl ProblemInputs {
         }
     }
 
+    pub fn years(&self) -> impl Iterator<Item = &str> {
+        self.inputs_map.keys().map(String::as_str)
+    }
+
+    /// Looks up the input for a single day, if there is one.
+    pub fn find(&self, year: &str, day: &str) -> Option<&ProblemInput> {
+        self.day(day, year)
+    }
+
     fn load_year(path: &str, year: &str) -> Result<BTreeMap<String, ProblemInput>, Box<dyn Error>> {
         let mut problem_inputs = BTreeMap::new();
 
@@ -68,7 +108,7 @@ impl ProblemInputs {
             if entry.file_type()?.is_file() {
                 let day = String::from(entry.file_name().to_string_lossy());
                 let day = day.split('.').next().expect("split results in at least 1 string");
-                let day_problem_input = ProblemInput { year: year.to_string(), day: day.to_string(), base_path: path.to_string() };
+                let day_problem_input = ProblemInput { year: year.to_string(), day: day.to_string(), base_path: path.to_string(), embedded: None };
                 problem_inputs.insert(day.to_string(), day_problem_input);
             }
         }
@@ -97,3 +137,16 @@ impl ProblemInputs {
         y.get(day)
     }
 }
+
+/// The number of puzzles in a year's event. From 2025 onwards there are 12 rather than 25.
+pub fn days_in_year(year: &str) -> u32 {
+    match year.parse::<u32>() {
+        Ok(year) if year >= 2025 => 12,
+        _ => 25,
+    }
+}
+
+/// Formats a day number the way inputs and solvers name it, eg. `01`.
+pub fn day_name(day: u32) -> String {
+    format!("{:02}", day)
+}
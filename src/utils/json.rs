@@ -0,0 +1,327 @@
+//!
+//! A minimal JSON value which can be written out, for reporting results to other tools,
+//! and read back, for passing results between processes.
+//!
+
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Json {
+    Null,
+    Bool(bool),
+    Int(i128),
+    Float(f64),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    /// Builds an object from key value pairs, keeping them in the given order.
+    pub fn object<K: Into<String>>(fields: impl IntoIterator<Item = (K, Json)>) -> Json {
+        Json::Object(fields.into_iter().map(|(k, v)| (k.into(), v)).collect())
+    }
+
+    /// The value of `key`, if this is an object with that key.
+    pub fn get(&self, key: &str) -> Option<&Json> {
+        match self {
+            Json::Object(fields) => fields.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Json::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn as_int(&self) -> Option<i128> {
+        match self {
+            Json::Int(i) => Some(*i),
+            _ => None,
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&[Json]> {
+        match self {
+            Json::Array(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    /// Parses a JSON document. Numbers without a fraction or exponent are read as `Int`.
+    pub fn parse(text: &str) -> Result<Json, String> {
+        let mut parser = Parser { text, offset: 0 };
+        let value = parser.value()?;
+        parser.skip_whitespace();
+        if parser.offset != text.len() {
+            return Err(parser.error("unexpected text after the value"));
+        }
+        Ok(value)
+    }
+}
+
+struct Parser<'a> {
+    text: &'a str,
+    offset: usize,
+}
+
+impl Parser<'_> {
+    fn error(&self, message: &str) -> String {
+        format!("{} at offset {}", message, self.offset)
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.text[self.offset..].chars().next()
+    }
+
+    fn skip_whitespace(&mut self) {
+        // Only the ASCII whitespace JSON allows, so each is a single byte.
+        while self.peek().is_some_and(|c| matches!(c, ' ' | '\t' | '\n' | '\r')) {
+            self.offset += 1;
+        }
+    }
+
+    fn expect(&mut self, literal: &str) -> Result<(), String> {
+        if !self.text[self.offset..].starts_with(literal) {
+            return Err(self.error(&format!("expected {:?}", literal)));
+        }
+        self.offset += literal.len();
+        Ok(())
+    }
+
+    fn value(&mut self) -> Result<Json, String> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some('n') => self.expect("null").map(|_| Json::Null),
+            Some('t') => self.expect("true").map(|_| Json::Bool(true)),
+            Some('f') => self.expect("false").map(|_| Json::Bool(false)),
+            Some('"') => self.string().map(Json::String),
+            Some('[') => {
+                self.offset += 1;
+                let mut values = vec![];
+                self.skip_whitespace();
+                if self.peek() == Some(']') {
+                    self.offset += 1;
+                    return Ok(Json::Array(values));
+                }
+                loop {
+                    values.push(self.value()?);
+                    self.skip_whitespace();
+                    match self.peek() {
+                        Some(',') => self.offset += 1,
+                        Some(']') => { self.offset += 1; return Ok(Json::Array(values)); },
+                        _ => return Err(self.error("expected ',' or ']'")),
+                    }
+                }
+            },
+            Some('{') => {
+                self.offset += 1;
+                let mut fields = vec![];
+                self.skip_whitespace();
+                if self.peek() == Some('}') {
+                    self.offset += 1;
+                    return Ok(Json::Object(fields));
+                }
+                loop {
+                    self.skip_whitespace();
+                    let key = self.string()?;
+                    self.skip_whitespace();
+                    self.expect(":")?;
+                    fields.push((key, self.value()?));
+                    self.skip_whitespace();
+                    match self.peek() {
+                        Some(',') => self.offset += 1,
+                        Some('}') => { self.offset += 1; return Ok(Json::Object(fields)); },
+                        _ => return Err(self.error("expected ',' or '}'")),
+                    }
+                }
+            },
+            Some(c) if c == '-' || c.is_ascii_digit() => self.number(),
+            _ => Err(self.error("expected a value")),
+        }
+    }
+
+    fn number(&mut self) -> Result<Json, String> {
+        let start = self.offset;
+        while self.peek().is_some_and(|c| c.is_ascii_digit() || matches!(c, '-' | '+' | '.' | 'e' | 'E')) {
+            self.offset += 1;
+        }
+        let number = &self.text[start..self.offset];
+        let parsed = if number.contains(['.', 'e', 'E']) {
+            number.parse().map(Json::Float).ok()
+        } else {
+            number.parse().map(Json::Int).ok()
+        };
+        parsed.ok_or_else(|| format!("invalid number {:?} at offset {}", number, start))
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        self.expect("\"")?;
+        let mut out = String::new();
+        loop {
+            let c = self.peek().ok_or_else(|| self.error("unterminated string"))?;
+            self.offset += c.len_utf8();
+            match c {
+                '"' => return Ok(out),
+                '\\' => {
+                    let escaped = self.peek().ok_or_else(|| self.error("unterminated string"))?;
+                    self.offset += 1;
+                    match escaped {
+                        '"' | '\\' | '/' => out.push(escaped),
+                        'n' => out.push('\n'),
+                        'r' => out.push('\r'),
+                        't' => out.push('\t'),
+                        'b' => out.push('\u{8}'),
+                        'f' => out.push('\u{c}'),
+                        'u' => {
+                            let hex = self.text.get(self.offset..self.offset + 4).ok_or_else(|| self.error("short \\u escape"))?;
+                            let code = u32::from_str_radix(hex, 16).map_err(|_| self.error("invalid \\u escape"))?;
+                            out.push(char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER));
+                            self.offset += 4;
+                        },
+                        _ => return Err(self.error("invalid escape")),
+                    }
+                },
+                c => out.push(c),
+            }
+        }
+    }
+}
+
+impl From<&str> for Json {
+    fn from(s: &str) -> Self { Json::String(s.to_string()) }
+}
+
+impl From<String> for Json {
+    fn from(s: String) -> Self { Json::String(s) }
+}
+
+impl From<bool> for Json {
+    fn from(b: bool) -> Self { Json::Bool(b) }
+}
+
+impl From<f64> for Json {
+    fn from(f: f64) -> Self { Json::Float(f) }
+}
+
+macro_rules! impl_from_int {
+    ($($t:ty)*) => ($(
+        impl From<$t> for Json {
+            fn from(i: $t) -> Self { Json::Int(i as i128) }
+        }
+    )*)
+}
+
+impl_from_int!(u8 u16 u32 u64 usize i8 i16 i32 i64 isize);
+
+impl<T: Into<Json>> From<Option<T>> for Json {
+    fn from(o: Option<T>) -> Self {
+        o.map_or(Json::Null, Into::into)
+    }
+}
+
+impl<T: Into<Json>> From<Vec<T>> for Json {
+    fn from(v: Vec<T>) -> Self {
+        Json::Array(v.into_iter().map(Into::into).collect())
+    }
+}
+
+impl fmt::Display for Json {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Json::Null => write!(f, "null"),
+            Json::Bool(b) => write!(f, "{}", b),
+            Json::Int(i) => write!(f, "{}", i),
+            Json::Float(x) if x.is_finite() => write!(f, "{}", x),
+            Json::Float(_) => write!(f, "null"),
+            Json::String(s) => write_string(f, s),
+            Json::Array(values) => {
+                write!(f, "[")?;
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 { write!(f, ",")?; }
+                    write!(f, "{}", value)?;
+                }
+                write!(f, "]")
+            },
+            Json::Object(fields) => {
+                write!(f, "{{")?;
+                for (i, (key, value)) in fields.iter().enumerate() {
+                    if i > 0 { write!(f, ",")?; }
+                    write_string(f, key)?;
+                    write!(f, ":{}", value)?;
+                }
+                write!(f, "}}")
+            },
+        }
+    }
+}
+
+fn write_string(f: &mut fmt::Formatter<'_>, s: &str) -> fmt::Result {
+    write!(f, "\"")?;
+    for c in s.chars() {
+        match c {
+            '"' => write!(f, "\\\"")?,
+            '\\' => write!(f, "\\\\")?,
+            '\n' => write!(f, "\\n")?,
+            '\r' => write!(f, "\\r")?,
+            '\t' => write!(f, "\\t")?,
+            c if (c as u32) < 0x20 => write!(f, "\\u{:04x}", c as u32)?,
+            c => write!(f, "{}", c)?,
+        }
+    }
+    write!(f, "\"")
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn write_scalars() {
+        assert_eq!(Json::Null.to_string(), "null");
+        assert_eq!(Json::from(true).to_string(), "true");
+        assert_eq!(Json::from(-12i32).to_string(), "-12");
+        assert_eq!(Json::from(1.5).to_string(), "1.5");
+        assert_eq!(Json::from(None::<u32>).to_string(), "null");
+    }
+
+    #[test]
+    fn write_escaped_string() {
+        assert_eq!(Json::from("a\"b\\c\nd\u{1}").to_string(), r#""a\"b\\c\nd\u0001""#);
+    }
+
+    #[test]
+    fn write_nested() {
+        let json = Json::object([
+            ("year", Json::from("2025")),
+            ("days", Json::Array(vec![Json::from(1u8), Json::from(2u8)])),
+            ("empty", Json::object::<&str>([])),
+        ]);
+        assert_eq!(json.to_string(), r#"{"year":"2025","days":[1,2],"empty":{}}"#);
+    }
+
+    #[test]
+    fn parse_round_trip() {
+        let json = Json::object([
+            ("text", Json::from("a\"b\\c\nd\u{1}é")),
+            ("values", Json::Array(vec![Json::Null, Json::from(true), Json::from(-12i64), Json::from(1.5)])),
+            ("empty", Json::object::<&str>([])),
+        ]);
+        assert_eq!(Json::parse(&json.to_string()), Ok(json.clone()));
+        assert_eq!(json.get("values").and_then(Json::as_array).map(<[Json]>::len), Some(4));
+        assert_eq!(Json::parse(" [ 1 , {\"a\" : \"b\"} ] ").unwrap().as_array().unwrap()[1].get("a").and_then(Json::as_str), Some("b"));
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(Json::parse("[1,"), Err("expected a value at offset 3".to_string()));
+        assert!(Json::parse("{\"a\" 1}").is_err());
+        assert!(Json::parse("1 2").is_err());
+        assert_eq!(Json::parse("\u{a0}1"), Err("expected a value at offset 0".to_string()));
+        assert!(Json::parse("[1,\u{a0}2]").is_err());
+    }
+}
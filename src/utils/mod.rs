@@ -1,7 +1,9 @@
+pub use advent_utils::{integer, log, math, parse, pattern, performance, solver, worksheet};
+
 pub mod inputs;
-pub mod solver;
-pub mod parse;
-pub mod integer;
-pub mod performance;
-pub mod math;
-pub mod debug;
\ No newline at end of file
+pub mod debug;
+pub mod answers;
+pub mod submit;
+pub mod json;
+pub mod alloc;
+pub mod trace;
\ No newline at end of file
@@ -0,0 +1,343 @@
+//!
+//! Submitting answers and keeping a local ledger of every attempt.
+//!
+//! The ledger is a tab separated file with one attempt per line, used to refuse
+//! answers which are already known to be wrong before they are submitted.
+//!
+
+use std::error::Error;
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::utils::solver::Part;
+
+pub const DEFAULT_ENDPOINT: &str = "https://adventofcode.com";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SubmitOutcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Incorrect,
+    /// Submitted too recently, with the wait time given in the response if there was one.
+    Wait(Option<String>),
+    AlreadySolved,
+    Unknown,
+}
+
+impl SubmitOutcome {
+    /// Reads the outcome from the body of the response to a submission.
+    pub fn from_response(body: &str) -> SubmitOutcome {
+        if body.contains("That's the right answer") {
+            SubmitOutcome::Correct
+        } else if body.contains("answer is too high") {
+            SubmitOutcome::TooHigh
+        } else if body.contains("answer is too low") {
+            SubmitOutcome::TooLow
+        } else if body.contains("That's not the right answer") {
+            SubmitOutcome::Incorrect
+        } else if body.contains("You gave an answer too recently") {
+            let wait = body
+                .split("You have ")
+                .nth(1)
+                .and_then(|rest| rest.split(" left to wait").next())
+                .map(String::from);
+            SubmitOutcome::Wait(wait)
+        } else if body.contains("You don't seem to be solving the right level") {
+            SubmitOutcome::AlreadySolved
+        } else {
+            SubmitOutcome::Unknown
+        }
+    }
+
+    /// Whether this outcome proves the submitted answer wrong.
+    pub fn is_wrong(&self) -> bool {
+        matches!(self, SubmitOutcome::TooHigh | SubmitOutcome::TooLow | SubmitOutcome::Incorrect)
+    }
+
+    fn label(&self) -> &str {
+        match self {
+            SubmitOutcome::Correct => "correct",
+            SubmitOutcome::TooHigh => "too-high",
+            SubmitOutcome::TooLow => "too-low",
+            SubmitOutcome::Incorrect => "incorrect",
+            SubmitOutcome::Wait(_) => "wait",
+            SubmitOutcome::AlreadySolved => "already-solved",
+            SubmitOutcome::Unknown => "unknown",
+        }
+    }
+
+    fn from_label(label: &str) -> SubmitOutcome {
+        match label {
+            "correct" => SubmitOutcome::Correct,
+            "too-high" => SubmitOutcome::TooHigh,
+            "too-low" => SubmitOutcome::TooLow,
+            "incorrect" => SubmitOutcome::Incorrect,
+            "wait" => SubmitOutcome::Wait(None),
+            "already-solved" => SubmitOutcome::AlreadySolved,
+            _ => SubmitOutcome::Unknown,
+        }
+    }
+}
+
+impl fmt::Display for SubmitOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SubmitOutcome::Correct => write!(f, "That's the right answer!"),
+            SubmitOutcome::TooHigh => write!(f, "Wrong, answer is too high"),
+            SubmitOutcome::TooLow => write!(f, "Wrong, answer is too low"),
+            SubmitOutcome::Incorrect => write!(f, "Wrong answer"),
+            SubmitOutcome::Wait(Some(wait)) => write!(f, "Answered too recently, wait {}", wait),
+            SubmitOutcome::Wait(None) => write!(f, "Answered too recently"),
+            SubmitOutcome::AlreadySolved => write!(f, "Already solved"),
+            SubmitOutcome::Unknown => write!(f, "Unrecognised response"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Attempt {
+    pub timestamp: u64,
+    pub year: String,
+    pub day: String,
+    pub part: Part,
+    pub outcome: SubmitOutcome,
+    pub answer: String,
+}
+
+impl Attempt {
+    fn parse(line: &str) -> Option<Attempt> {
+        let [timestamp, year, day, part, outcome, answer]: [&str; 6] = line
+            .splitn(6, '\t')
+            .collect::<Vec<_>>()
+            .try_into()
+            .ok()?;
+
+        Some(Attempt {
+            timestamp: timestamp.parse().ok()?,
+            year: year.to_string(),
+            day: day.to_string(),
+            part: part.parse().ok()?,
+            outcome: SubmitOutcome::from_label(outcome),
+            answer: answer.to_string(),
+        })
+    }
+
+    fn to_line(&self) -> String {
+        format!(
+            "{}\t{}\t{}\t{}\t{}\t{}",
+            self.timestamp, self.year, self.day, self.part, self.outcome.label(), self.answer
+        )
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Ledger {
+    path: String,
+    attempts: Vec<Attempt>,
+}
+
+impl Ledger {
+
+    /// Loads the ledger at `path`. A missing file is treated as an empty ledger.
+    pub fn load_from_path(path: &str) -> Result<Ledger, Box<dyn Error>> {
+        let attempts = if Path::new(path).is_file() {
+            Self::parse(&fs::read_to_string(path)?)
+        } else {
+            vec![]
+        };
+        Ok(Ledger { path: path.to_string(), attempts })
+    }
+
+    pub fn attempts(&self, year: &str, day: &str, part: Part) -> impl Iterator<Item = &Attempt> {
+        self.attempts
+            .iter()
+            .filter(move |a| a.year == year && a.day == day && a.part == part)
+    }
+
+    /// Finds an earlier attempt which shows `answer` is wrong, either because the same
+    /// answer was rejected or because it is outside a too high/too low bound.
+    pub fn known_wrong(&self, year: &str, day: &str, part: Part, answer: &str) -> Option<&Attempt> {
+        let value = answer.parse::<i128>().ok();
+
+        self.attempts(year, day, part).find(|attempt| {
+            if attempt.answer == answer {
+                return attempt.outcome.is_wrong();
+            }
+            let (Some(value), Ok(bound)) = (value, attempt.answer.parse::<i128>()) else {
+                return false;
+            };
+            match attempt.outcome {
+                SubmitOutcome::TooHigh => value >= bound,
+                SubmitOutcome::TooLow => value <= bound,
+                _ => false,
+            }
+        })
+    }
+
+    /// Appends an attempt to the ledger file.
+    pub fn record(&mut self, year: &str, day: &str, part: Part, answer: &str, outcome: SubmitOutcome) -> Result<(), Box<dyn Error>> {
+        let timestamp = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+        let attempt = Attempt {
+            timestamp,
+            year: year.to_string(),
+            day: day.to_string(),
+            part,
+            outcome,
+            answer: answer.to_string(),
+        };
+
+        if let Some(dir) = Path::new(&self.path).parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut file = OpenOptions::new().create(true).append(true).open(&self.path)?;
+        writeln!(file, "{}", attempt.to_line())?;
+
+        self.attempts.push(attempt);
+        Ok(())
+    }
+
+    fn parse(text: &str) -> Vec<Attempt> {
+        text.lines().filter_map(Attempt::parse).collect()
+    }
+}
+
+/// Posts answers to an Advent of Code compatible endpoint using `curl`.
+pub struct Submitter {
+    pub endpoint: String,
+    pub session: String,
+}
+
+impl Submitter {
+    pub fn submit(&self, year: &str, day: &str, part: Part, answer: &str) -> Result<SubmitOutcome, Box<dyn Error>> {
+        let url = format!(
+            "{}/{}/day/{}/answer",
+            self.endpoint.trim_end_matches('/'),
+            year,
+            day.trim_start_matches('0')
+        );
+
+        // The session cookie goes through curl's config on stdin, as anyone can read the
+        // command line of a running process.
+        let mut curl = Command::new("curl")
+            .arg("--silent")
+            .arg("--show-error")
+            .arg("--fail")
+            .args(["--config", "-"])
+            .args(["--data", &format!("level={}", part)])
+            .args(["--data-urlencode", &format!("answer={}", answer)])
+            .arg(&url)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+        curl.stdin.take().expect("curl stdin is piped").write_all(self.curl_config().as_bytes())?;
+        let output = curl.wait_with_output()?;
+
+        if !output.status.success() {
+            return Err(format!(
+                "Submitting to {} failed: {}",
+                url,
+                String::from_utf8_lossy(&output.stderr).trim()
+            ).into());
+        }
+
+        Ok(SubmitOutcome::from_response(&String::from_utf8_lossy(&output.stdout)))
+    }
+
+    /// The curl config giving the session cookie, quoted as curl's config files expect.
+    fn curl_config(&self) -> String {
+        let quoted = self.session.replace('\\', "\\\\").replace('"', "\\\"");
+        format!("cookie = \"session={}\"\n", quoted)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ledger(lines: &[&str]) -> Ledger {
+        Ledger { path: String::new(), attempts: Ledger::parse(&lines.join("\n")) }
+    }
+
+    #[test]
+    fn outcome_correct() {
+        let body = "<article><p>That's the right answer!  You are one gold star closer.</p></article>";
+        assert_eq!(SubmitOutcome::from_response(body), SubmitOutcome::Correct);
+    }
+
+    #[test]
+    fn outcome_too_high() {
+        let body = "<p>That's not the right answer; your answer is too high.</p>";
+        assert_eq!(SubmitOutcome::from_response(body), SubmitOutcome::TooHigh);
+    }
+
+    #[test]
+    fn outcome_too_low() {
+        let body = "<p>That's not the right answer; your answer is too low.</p>";
+        assert_eq!(SubmitOutcome::from_response(body), SubmitOutcome::TooLow);
+    }
+
+    #[test]
+    fn outcome_incorrect() {
+        let body = "<p>That's not the right answer.  If you're stuck...</p>";
+        assert_eq!(SubmitOutcome::from_response(body), SubmitOutcome::Incorrect);
+    }
+
+    #[test]
+    fn outcome_wait() {
+        let body = "<p>You gave an answer too recently; you have to wait. You have 4m 12s left to wait.</p>";
+        assert_eq!(SubmitOutcome::from_response(body), SubmitOutcome::Wait(Some("4m 12s".to_string())));
+    }
+
+    #[test]
+    fn attempt_line_round_trip() {
+        let attempt = Attempt {
+            timestamp: 1733011200,
+            year: "2025".to_string(),
+            day: "01".to_string(),
+            part: Part::Two,
+            outcome: SubmitOutcome::TooLow,
+            answer: "1234".to_string(),
+        };
+        assert_eq!(Attempt::parse(&attempt.to_line()), Some(attempt));
+    }
+
+    #[test]
+    fn known_wrong_same_answer() {
+        let ledger = ledger(&["1\t2025\t01\t1\tincorrect\tabc"]);
+        assert!(ledger.known_wrong("2025", "01", Part::One, "abc").is_some());
+        assert!(ledger.known_wrong("2025", "01", Part::One, "abd").is_none());
+        assert!(ledger.known_wrong("2025", "01", Part::Two, "abc").is_none());
+        assert!(ledger.known_wrong("2025", "02", Part::One, "abc").is_none());
+    }
+
+    #[test]
+    fn known_wrong_bounds() {
+        let ledger = ledger(&[
+            "1\t2025\t01\t1\ttoo-high\t100",
+            "2\t2025\t01\t1\ttoo-low\t10",
+        ]);
+        assert!(ledger.known_wrong("2025", "01", Part::One, "100").is_some());
+        assert!(ledger.known_wrong("2025", "01", Part::One, "150").is_some());
+        assert!(ledger.known_wrong("2025", "01", Part::One, "5").is_some());
+        assert!(ledger.known_wrong("2025", "01", Part::One, "50").is_none());
+    }
+
+    #[test]
+    fn known_wrong_ignores_wait() {
+        let ledger = ledger(&["1\t2025\t01\t1\twait\t42"]);
+        assert!(ledger.known_wrong("2025", "01", Part::One, "42").is_none());
+    }
+
+    #[test]
+    fn curl_config_quotes_session() {
+        let submitter = Submitter { endpoint: String::new(), session: r#"ab"c\d"#.to_string() };
+        assert_eq!(submitter.curl_config(), "cookie = \"session=ab\\\"c\\\\d\"\n");
+    }
+}
@@ -0,0 +1,157 @@
+//!
+//! Structured trace events which solvers can emit while they run, instead of adding
+//! and removing `println!`s when hunting down a wrong answer.
+//!
+//! Events are only recorded inside `capture`, which the runner uses for each run when
+//! tracing is requested. Outside of it `trace_event!` costs a single atomic load.
+//!
+
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::utils::json::Json;
+
+static ACTIVE_CAPTURES: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    static EVENTS: RefCell<Option<Vec<Event>>> = const { RefCell::new(None) };
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Event {
+    pub name: &'static str,
+    pub fields: Vec<(&'static str, Json)>,
+}
+
+impl Event {
+    pub fn to_json(&self, seq: usize) -> Json {
+        Json::object([
+            ("seq", Json::from(seq)),
+            ("name", Json::from(self.name)),
+            ("fields", Json::object(self.fields.iter().map(|(k, v)| (*k, v.clone())))),
+        ])
+    }
+}
+
+/// Whether any thread is capturing events. Checked by `trace_event!` before building an event.
+#[inline]
+pub fn is_enabled() -> bool {
+    ACTIVE_CAPTURES.load(Ordering::Relaxed) > 0
+}
+
+/// Records an event if the current thread is capturing.
+pub fn record(name: &'static str, fields: Vec<(&'static str, Json)>) {
+    EVENTS.with(|events| {
+        if let Some(events) = events.borrow_mut().as_mut() {
+            events.push(Event { name, fields });
+        }
+    });
+}
+
+/// Runs `f`, returning its result along with every event it recorded on this thread.
+pub fn capture<T>(f: impl FnOnce() -> T) -> (T, Vec<Event>) {
+    struct Guard(Option<Vec<Event>>);
+
+    impl Drop for Guard {
+        fn drop(&mut self) {
+            EVENTS.with(|events| *events.borrow_mut() = self.0.take());
+            ACTIVE_CAPTURES.fetch_sub(1, Ordering::Relaxed);
+        }
+    }
+
+    let previous = EVENTS.with(|events| events.borrow_mut().replace(vec![]));
+    ACTIVE_CAPTURES.fetch_add(1, Ordering::Relaxed);
+    let guard = Guard(previous);
+
+    let result = f();
+    let events = EVENTS.with(|events| events.borrow_mut().take()).unwrap_or_default();
+    drop(guard);
+    (result, events)
+}
+
+/// Keeps only the events with one of the given names.
+pub fn filter<'a>(events: &'a [Event], names: &'a [&str]) -> impl Iterator<Item = &'a Event> {
+    events.iter().filter(move |e| names.contains(&e.name))
+}
+
+/// Writes events as JSON lines, numbered in the order they were recorded.
+pub fn to_json_lines<'a>(events: impl IntoIterator<Item = &'a Event>) -> String {
+    events
+        .into_iter()
+        .enumerate()
+        .map(|(seq, event)| format!("{}\n", event.to_json(seq)))
+        .collect()
+}
+
+/// Emits a named trace event with key/value fields. Values can be anything convertible to `Json`.
+///
+/// ```
+/// use advent::trace_event;
+/// use advent::utils::trace;
+///
+/// let (_, events) = trace::capture(|| {
+///     trace_event!("pivot", row = 1, col = 3);
+/// });
+/// assert_eq!(trace::to_json_lines(&events), "{\"seq\":0,\"name\":\"pivot\",\"fields\":{\"row\":1,\"col\":3}}\n");
+/// ```
+#[macro_export]
+macro_rules! trace_event {
+    ($name:expr $(, $key:ident = $value:expr)* $(,)?) => {
+        if $crate::utils::trace::is_enabled() {
+            $crate::utils::trace::record(
+                $name,
+                vec![$((stringify!($key), $crate::utils::json::Json::from($value))),*],
+            );
+        }
+    };
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn events_outside_capture_are_dropped() {
+        trace_event!("ignored", value = 1);
+        let (_, events) = capture(|| ());
+        assert!(events.is_empty());
+    }
+
+    #[test]
+    fn capture_records_in_order() {
+        let (result, events) = capture(|| {
+            trace_event!("a", x = 1);
+            trace_event!("b", name = "n", values = vec![1, 2]);
+            7
+        });
+        assert_eq!(result, 7);
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[1], Event {
+            name: "b",
+            fields: vec![("name", Json::from("n")), ("values", Json::Array(vec![Json::from(1), Json::from(2)]))],
+        });
+    }
+
+    #[test]
+    fn nested_capture_restores_outer() {
+        let (_, outer) = capture(|| {
+            trace_event!("outer");
+            let (_, inner) = capture(|| trace_event!("inner"));
+            assert_eq!(inner.len(), 1);
+            trace_event!("outer");
+        });
+        assert_eq!(outer.iter().map(|e| e.name).collect::<Vec<_>>(), vec!["outer", "outer"]);
+    }
+
+    #[test]
+    fn filter_by_name() {
+        let (_, events) = capture(|| {
+            trace_event!("a");
+            trace_event!("b");
+            trace_event!("a");
+        });
+        assert_eq!(filter(&events, &["a"]).count(), 2);
+        assert_eq!(to_json_lines(filter(&events, &["b"])), "{\"seq\":0,\"name\":\"b\",\"fields\":{}}\n");
+    }
+}
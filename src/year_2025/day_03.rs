@@ -1,4 +1,5 @@
 use crate::define_solver;
+use crate::utils::parse::AocParseExt;
 
 define_solver!(
     Day3Solver,
@@ -7,7 +8,8 @@ define_solver!(
     String,
     preprocess,
     part_one,
-    part_two
+    part_two,
+    line_order_independent
 );
 
 fn preprocess(input: &str) -> String {
@@ -25,11 +27,7 @@ fn part_two(input: &str) -> String {
 fn solve(input: &str, digit_count: usize) -> String {
     input.lines()
         .into_iter()
-        .map(|bank| 
-            bank.chars()
-                .map(|x| x.to_digit(10).expect("all chars in each line should be digits"))
-                .collect()
-        )
+        .map(|bank| bank.as_digit_iter().collect())
         .fold(0, |sum, bank| sum + find_max_digits(bank, digit_count))
         .to_string()
 }
@@ -1,9 +1,10 @@
-use crate::utils::parse::{AocParseExt, IntParser};
-use crate::define_solver;
+use crate::utils::parse::AocParseExt;
+use crate::{define_solver, parse_pattern};
 
 #[derive(Debug, Clone)]
 struct Range { start: u64, end: u64 }
 
+#[derive(Debug)]
 struct Ingredients {
     sorted_fresh_id_ranges: Vec<Range>,
     ingredient_ids: Vec<u64>
@@ -20,25 +21,18 @@ define_solver!(
 );
 
 fn preprocess(input: &str) -> Ingredients {
-    let [input_ranges, input_ids]: [&str; 2] = input
-        .split("\n\n")
-        .take(2)
-        .collect::<Vec<_>>()
-        .try_into()
-        .expect("Expected exactly 2 sections");
+    let [input_ranges, input_ids] = input.sections().exactly::<2>().unwrap_or_else(|err| panic!("{}", err));
 
-    let mut ranges_parser: IntParser<u64> = input_ranges.as_unsigned_iter();
-    let mut ranges: Vec<Range> = vec![];
-    loop {
-        match (ranges_parser.next(), ranges_parser.next()) {
-            (Some(start), Some(end)) => ranges.push(Range { start, end }),
-            (None, None) => break,
-            _ => panic!("Each start of range sould have a corresponding end"),
-        };
-    }
+    let mut ranges = input_ranges
+        .parse(|text| {
+            text.lines()
+                .map(|line| parse_pattern!("{}-{}", line => u64, u64).map(|(start, end)| Range { start, end }))
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .unwrap_or_else(|err| panic!("{}", err));
     ranges.sort_by_key(|r| r.start);
 
-    let ingredient_ids: Vec<u64> = input_ids.as_unsigned_iter().collect();
+    let ingredient_ids: Vec<u64> = input_ids.text.as_unsigned_iter().collect();
 
     Ingredients { 
         sorted_fresh_id_ranges: ranges, 
@@ -1,97 +1,68 @@
 use crate::define_solver;
+use crate::utils::parse::Diagnostic;
+use crate::utils::worksheet::{Block, Worksheet};
 
 define_solver!(
     Day6Solver,
     "2025",
     "06",
-    String,
+    Vec<Block>,
     preprocess,
     part_one,
-    part_two
+    part_two,
+    fallible
 );
 
-fn preprocess(input: &str) -> String {
-    String::from(input)
+fn preprocess(input: &str) -> Result<Vec<Block>, Box<Diagnostic>> {
+    let blocks = Worksheet::new(input).blocks();
+    for block in &blocks {
+        let operators = &block.rows[block.rows.len() - 1];
+        if !matches!(operators.trim(), "*" | "+") {
+            // Point at the operator if there is one, or underline the whole block's row if not.
+            let (offset, width) = match operators.find(|c: char| !c.is_whitespace()) {
+                Some(offset) => (offset, operators.trim().chars().count()),
+                None => (0, block.columns.len()),
+            };
+            return Err(Diagnostic::at_line(input, block.rows.len(), block.start + offset + 1, format!("Unknown operator {:?}", operators.trim()))
+                .with_width(width)
+                .with_hint("each problem ends with a '*' or '+' on the last line")
+                .into());
+        }
+    }
+    Ok(blocks)
 }
 
-fn part_two(input: &str) -> String {
-
-    let rows: Vec<Vec<&str>> = input
-        .lines()
-        .into_iter()
-        .map(|x| x.split("").filter(|&x| x != "").collect())
-        .collect();
-
-    let mut curr_operator = "";
-    let mut curr_value: u64 = 0;
-    let total = (0..rows[0].len()).fold(0, |sum, i| {
-        let col_operator = rows[rows.len() - 1][i];
-
-        let is_spacer_col = rows
+fn part_one(blocks: &[Block]) -> String {
+    // Numbers are read along each row, above the operator row.
+    solve(blocks, |block| {
+        block.rows[..block.rows.len() - 1]
             .iter()
-            .filter(|row| row[i] != " ")
-            .count() == 0;
-
-        if is_spacer_col {
-            return sum + curr_value;
-        }
-
-        match col_operator {
-            "*" => {
-                curr_operator = col_operator;
-                curr_value = 1;
-            },
-            "+" => {
-                curr_operator = col_operator;
-                curr_value = 0;
-            },
-            _ => {}
-        }
-
-        let column_num: u64 = rows[0..rows.len() - 1]
-            .into_iter()
-            .filter(|row| row[i].trim() != "")
-            .fold("".to_string(), |acc, row| format!("{}{}", acc, row[i]))
-            .parse::<u64>()
-            .unwrap();
-
-        match curr_operator {
-            "*" => {
-                curr_value = curr_value * column_num;
-            },
-            "+" => {
-                curr_value = curr_value + column_num;
-            },
-            op => panic!("Unknown operator {}", op)
-        }
-
-        sum
-    });
-
-    (total + curr_value).to_string()
+            .map(|row| row.trim().parse().unwrap())
+            .collect()
+    })
 }
 
+fn part_two(blocks: &[Block]) -> String {
+    // Numbers are read down each column, leaving out the operator row at the bottom.
+    solve(blocks, |block| {
+        block.columns
+            .iter()
+            .map(|column| column[..column.len() - 1].trim().parse().unwrap())
+            .collect()
+    })
+}
 
-fn part_one(input: &str) -> String {
-    let nums: Vec<Vec<&str>> = input
-        .lines()
-        .into_iter()
-        .map(|line| line.split(" ").filter(|x| x.trim() != "").collect())
-        .collect();
-
-    let out= (0..nums[0].len())
-        .map(|i| {
-            match nums[nums.len() - 1][i] {
-                "*" => {
-                    nums[0..nums.len() - 1].into_iter().fold(1, |acc, rows| acc * rows[i].parse::<u64>().unwrap())
-                },
-                "+" => {
-                    nums[0..nums.len() - 1].into_iter().fold(0, |acc, rows| acc + rows[i].parse::<u64>().unwrap())
-                },
-                op => panic!("Unknown operator {}", op)
-
+fn solve(blocks: &[Block], numbers: impl Fn(&Block) -> Vec<u64>) -> String {
+    blocks
+        .iter()
+        .map(|block| {
+            let nums = numbers(block);
+            match block.rows[block.rows.len() - 1].trim() {
+                "*" => nums.iter().product::<u64>(),
+                "+" => nums.iter().sum::<u64>(),
+                op => unreachable!("preprocess rejects {:?}", op),
             }
         })
-        .fold(0u64, |total, col_total| total + col_total);
-    out.to_string()
+        .sum::<u64>()
+        .to_string()
 }
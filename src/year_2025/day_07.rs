@@ -1,4 +1,4 @@
-use crate::utils::parse::{AocParseExt};
+use crate::utils::parse::{AocParseExt, Diagnostic};
 use crate::define_solver;
 use std::collections::HashMap;
 
@@ -11,13 +11,23 @@ define_solver!(
     TachyonManifold,
     preprocess,
     part_one,
-    part_two
+    part_two,
+    fallible
 );
 
-fn preprocess(input: &str) -> TachyonManifold {
+fn preprocess(input: &str) -> Result<TachyonManifold, Box<Diagnostic>> {
     let grid: Vec<Vec<_>> = input.to_char_grid();
-    let start_pos = grid[0].iter().position(|&c| c == 'S').unwrap();
-    (grid, start_pos)
+    for (i, row) in grid.iter().enumerate() {
+        if let Some(j) = row.iter().position(|c| !matches!(c, 'S' | '^' | '.')) {
+            return Err(Diagnostic::at_line(input, i + 1, j + 1, format!("Unexpected char {:?}", row[j]))
+                .with_hint("the manifold should only contain 'S', '^' and '.'")
+                .into());
+        }
+    }
+    let start_pos = grid.first().and_then(|row| row.iter().position(|&c| c == 'S')).ok_or_else(|| {
+        Diagnostic::at_line(input, 1, 1, "No start position").with_hint("the beam enters at the 'S' on the first line")
+    })?;
+    Ok((grid, start_pos))
 }
 
 fn part_one((char_grid, start_pos): &TachyonManifold) -> String  {
@@ -52,7 +62,7 @@ fn get_beam_splits(grid: &Vec<Vec<char>>, i: i32, j: i32, memo: &mut HashMap<Str
         'S' => get_beam_splits(grid, i + 1, j, memo),
         '^' => 1 + get_beam_splits(grid, i, j + 1, memo) + get_beam_splits(grid, i, j - 1, memo),
         '.' => get_beam_splits(grid, i + 1, j, memo),
-        ch => panic!("Unexpected char {}", ch)
+        ch => unreachable!("preprocess rejects {:?}", ch)
     }
 }
 
@@ -78,7 +88,7 @@ fn get_beam_quantum_splits(grid: &Vec<Vec<char>>, i: i32, j: i32, memo: &mut Has
             '.' => { 
                 total_paths = get_beam_quantum_splits(grid, i + 1, j, memo);
             }
-            ch => panic!("Unexpected char {}", ch)
+            ch => unreachable!("preprocess rejects {:?}", ch)
         }
     }
 
@@ -107,5 +117,13 @@ mod tests {
         let mut memo = HashMap::new();
         assert_eq!(get_beam_splits(&mut grid, 0, 6, &mut memo), 5);
     }
+
+    #[test]
+    fn rejects_unexpected_char() {
+        let diagnostic = preprocess("..S..\n..^..\n.^#^.\n").unwrap_err();
+        assert_eq!((diagnostic.line, diagnostic.column), (3, 3));
+        assert_eq!(diagnostic.message, "Unexpected char '#'");
+        assert_eq!(preprocess("...\n").unwrap_err().message, "No start position");
+    }
 }
 
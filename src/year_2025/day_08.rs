@@ -1,8 +1,10 @@
 use crate::define_solver;
 use std::collections::{HashSet};
-use crate::utils::parse::{AocParseExt, IntParser};
+use crate::utils::parse::{AocParseExt, Diagnostic};
+use crate::utils::pattern::AocParse;
 
-#[derive(Clone)]
+#[derive(Clone, AocParse)]
+#[aoc(sep = ",")]
 struct Junction {
     x: i64,
     y: i64,
@@ -32,11 +34,13 @@ define_solver!(
     (String, String),
     preprocess,
     part_one,
-    part_two
+    part_two,
+    line_order_independent,
+    fallible
 );
 
-fn preprocess(input: &str) -> (String, String) {
-    solve(input)
+fn preprocess(input: &str) -> Result<(String, String), Box<Diagnostic>> {
+    Ok(solve(input.parse_records()?))
 }
 
 fn part_one((one, _): &(String, String)) -> String {
@@ -47,14 +51,7 @@ fn part_two((_, two): &(String, String)) -> String {
     String::from(two)
 }
 
-fn solve(input: &str) -> (String, String) {
-    let mut parser: IntParser<i64> = input.as_signed_iter();
-
-    let mut junctions: Vec<Junction> = vec![];
-    while let (Some(x), Some(y), Some(z)) = (parser.next(),parser.next(), parser.next()) {
-        junctions.push(Junction { x, y, z})
-    }
-
+fn solve(junctions: Vec<Junction>) -> (String, String) {
     let mut junction_distances: Vec<JunctionPairDistance> = vec![];
         
     for i in 0..junctions.len() {
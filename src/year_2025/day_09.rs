@@ -1,4 +1,4 @@
-use crate::define_solver;
+use crate::{define_solver, parse_pattern};
 use std::{cmp::Reverse};
 
 define_solver!(
@@ -126,10 +126,8 @@ fn solve(input: &str) -> (String, String) {
     let coords: Vec<Coord> = input
         .lines()
         .map(|line| {
-            let parts: Vec<&str> = line.split(',').collect();
-            let x = parts[0].parse::<i64>().unwrap();
-            let y = parts[1].parse::<i64>().unwrap();
-            return Coord { x, y };
+            let (x, y) = parse_pattern!("{},{}", line => i64, i64).unwrap_or_else(|err| panic!("{}", err));
+            Coord { x, y }
         })
         .collect();
 
@@ -1,6 +1,6 @@
-use crate::define_solver;
+use crate::{define_solver, trace_event};
 use std::collections::{BTreeSet};
-use crate::utils::parse::{AocParseExt};
+use crate::utils::parse::{tokenize_lines, Delimiter, Diagnostic, Token};
 use itertools::Itertools;
 
 define_solver!(
@@ -10,15 +10,19 @@ define_solver!(
     Vec<Machine>,
     preprocess,
     part_one,
-    part_two
+    part_two,
+    line_order_independent,
+    fallible
 );
 
 type Machine = (usize, Vec<usize>, Vec<i32>);
 
-fn preprocess(input: &str) -> Vec<Machine> {
-    input
-        .lines()
-        .map(parse_machine)
+fn preprocess(input: &str) -> Result<Vec<Machine>, Box<Diagnostic>> {
+    tokenize_lines(input)?
+        .into_iter()
+        .enumerate()
+        .filter(|(_, tokens)| !tokens.is_empty())
+        .map(|(i, tokens)| parse_machine(tokens).map_err(|message| Box::new(Diagnostic::whole_line(input, i + 1, message))))
         .collect()
 }
 
@@ -30,24 +34,31 @@ fn part_two(machines: &Vec<Machine>) -> String {
     configure_joltages_sum(machines).to_string()
 }
 
-fn parse_machine(line: &str) -> Machine {
-    let tokens: Vec<_> = line.split_ascii_whitespace().collect();
-    let last = tokens.len() - 1;
-
-    let lights = tokens[0]
-        .bytes()
-        .skip(1)
-        .enumerate()
-        .fold(0, |light, (i, b)| light | (usize::from(b == b'#') << i));
+/// Reads a machine from its `[lights]`, `(button)` and `{joltages}` groups, in any order.
+fn parse_machine(tokens: Vec<Token>) -> Result<Machine, String> {
+    let (mut lights, mut buttons, mut joltages) = (None, vec![], None);
 
-    let buttons = tokens[1..last]
-        .iter()
-        .map(|token| token.as_unsigned_iter::<usize>().fold(0, |button, i| button | (1 << i)))
-        .collect();
+    for token in tokens {
+        match token {
+            Token::Group { delimiter: Delimiter::Bracket, items } => {
+                let [Token::Word(diagram)] = items[..] else {
+                    return Err("lights should be a single diagram of '.' and '#'".to_string());
+                };
+                lights = Some(diagram.bytes().enumerate().fold(0, |light, (i, b)| light | (usize::from(b == b'#') << i)));
+            },
+            Token::Group { delimiter: Delimiter::Paren, items } => {
+                buttons.push(numbers::<usize>(&items)?.into_iter().fold(0, |button, i| button | (1 << i)));
+            },
+            Token::Group { delimiter: Delimiter::Brace, items } => joltages = Some(numbers(&items)?),
+            token => return Err(format!("unexpected {}", token)),
+        }
+    }
 
-    let joltages = tokens[last].as_signed_iter::<i32>().collect();
+    Ok((lights.ok_or("missing [lights]")?, buttons, joltages.ok_or("missing {joltages}")?))
+}
 
-    (lights, buttons, joltages)
+fn numbers<T: std::str::FromStr>(items: &[Token]) -> Result<Vec<T>, String> {
+    items.iter().map(|item| item.parse().ok_or_else(|| format!("expected a number, found {}", item))).collect()
 }
 
 fn configure_lights_sum(machines: &Vec<Machine>) -> u32 {
@@ -96,6 +107,7 @@ fn configure_joltage(machine: &Machine) -> i32 {
     // Ideally we want to reduce the pivot col to 1 to make the rest of the algorithm simpler.
     // We may need to iterate multiple times to achieve row echelon form in some cases.
     let (free_var_cols, equations) = reduce_linear_system(width, height, &mut equations);
+    trace_event!("free_vars", cols = free_var_cols.iter().copied().collect::<Vec<_>>());
 
     // Step 3: Brute force search the free variables
     // Our starting point is that all free variables are equal to zero, and 
@@ -165,6 +177,8 @@ fn reduce_linear_system(width: usize, height: usize, equations: &mut Vec<Vec<i32
                     continue;
                 };
 
+            trace_event!("pivot", row = pivot_row, col = pivot_col, selected = selected + pivot_row);
+
             // Move the pivot row to its required location above all other pivot rows.
             equations.swap(pivot_row, selected + pivot_row);
 
@@ -305,6 +319,8 @@ fn optimise_free_vars(OptimiseFreeVarsInput {
             }
         }
 
+        trace_event!("free_var_bounds", index = current_index, min = free_var_min, max = free_var_max);
+
         // No solution
         if free_var_min > free_var_max {
             return None;
@@ -374,6 +390,15 @@ mod tests {
         assert_eq!(Day10Solver.solve(input_3).0, "2");
     }
 
+    #[test]
+    fn parse_machine_groups_in_any_order() {
+        let machines = preprocess("{3,5}  (1) [.#]( 0 ,1 )\n").unwrap();
+        assert_eq!(machines, vec![(2, vec![2, 3], vec![3, 5])]);
+
+        let err = preprocess("[.#] (1) {3,5}\n[.#] (x) {3}\n").unwrap_err();
+        assert_eq!((err.line, err.message.as_str()), (2, "expected a number, found x"));
+    }
+
     #[test]
     fn configure_joltages_simple() {
         let input = "[#.##] (0) (0,2,3) (1,2) (2,3) {15,8,19,11}";
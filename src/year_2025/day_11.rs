@@ -1,89 +1,65 @@
 use crate::define_solver;
-use std::collections::HashMap;
+use crate::utils::parse::{Diagnostic, Direction, Graph};
 
 define_solver!(
     Day11Solver,
     "2025",
     "11",
-    HashMap<String, Device>,
+    Graph,
     preprocess,
     part_one,
-    part_two
+    part_two,
+    line_order_independent,
+    fallible
 );
 
-fn preprocess(input: &str) -> HashMap<String, Device> {
-    let mut device_map: HashMap<String, Device> = HashMap::new();
-
-    let devices: Vec<Device> = input
-        .lines()
-        .map(|line| {
-            let parts: Vec<&str> = line.split(":").collect();
-
-            let id = parts[0].to_string();
-            let outputs: Vec<_> = parts[1]
-                .split(' ')
-                .filter(|&p| p.len() > 0)
-                .map(|p| p.to_string())
-                .collect();
-
-            Device { id, outputs }
-        })
-        .collect();
-
-    for d in devices {
-        device_map.insert(d.id.clone(), d);
+fn preprocess(input: &str) -> Result<Graph, Box<Diagnostic>> {
+    let mut devices = Graph::parse(input, Direction::Directed)?;
+    // "out" has no outputs of its own, so it only appears if something leads to it.
+    devices.intern("out");
+    if let Some(missing) = ["you", "svr", "fft", "dac"].into_iter().find(|&name| devices.id(name).is_none()) {
+        return Err(Diagnostic::at_line(input, 1, 1, format!("No device named {}", missing))
+            .with_hint("the paths start from \"you\" and \"svr\", and pass through \"fft\" and \"dac\"")
+            .into());
     }
-
-    device_map.insert("out".to_string(), Device { id: "out".to_string(), outputs: vec![] });
-    device_map
+    Ok(devices)
 }
 
-fn part_one(device_map: &HashMap<String, Device>) -> String {
-    get_paths_to_out(device_map).to_string()
+fn part_one(devices: &Graph) -> String {
+    get_paths_to_out(devices).to_string()
 }
 
-fn part_two(device_map: &HashMap<String, Device>) -> String {
-    get_paths_part_two(device_map).to_string()
+fn part_two(devices: &Graph) -> String {
+    get_paths_part_two(devices).to_string()
 }
 
-fn get_paths_to_out(device_map: &HashMap<String, Device>) -> u64 {
-    device_map["you"].path_count("out", &device_map, &mut HashMap::new())
+fn get_paths_to_out(devices: &Graph) -> u64 {
+    paths(devices, "you", "out")
 }
 
-fn get_paths_part_two(device_map: &HashMap<String, Device>) -> u64 {
-    let paths_svr_fft = device_map["svr"].path_count("fft", &device_map, &mut HashMap::new());
-    let paths_fft_dac = device_map["fft"].path_count("dac", &device_map, &mut HashMap::new());
-    let paths_dac_out = device_map["dac"].path_count("out", &device_map, &mut HashMap::new());
-
-    let route_1_total = paths_svr_fft * paths_fft_dac * paths_dac_out;
-
-    let paths_svr_dac = device_map["svr"].path_count("dac", &device_map, &mut HashMap::new());
-    let paths_dac_fft = device_map["dac"].path_count("fft", &device_map, &mut HashMap::new());
-    let paths_fft_out = device_map["fft"].path_count("out", &device_map, &mut HashMap::new());
-    
-    let route_2_total = paths_svr_dac * paths_dac_fft * paths_fft_out;
+fn get_paths_part_two(devices: &Graph) -> u64 {
+    let route_1_total = paths(devices, "svr", "fft") * paths(devices, "fft", "dac") * paths(devices, "dac", "out");
+    let route_2_total = paths(devices, "svr", "dac") * paths(devices, "dac", "fft") * paths(devices, "fft", "out");
 
     route_1_total + route_2_total
 }
 
-struct Device {
-    id: String,
-    outputs: Vec<String>,
+fn paths(devices: &Graph, from: &str, to: &str) -> u64 {
+    let id = |name| devices.id(name).expect("preprocess checks every device is named");
+    path_count(devices, id(from), id(to), &mut vec![None; devices.len()])
 }
 
-impl Device {
-    fn path_count(&self, id: &str, device_map: &HashMap<String, Device>, memo_map: &mut HashMap<String, u64>) -> u64 {
-        let memoised = memo_map.get(&self.id);
-        if memoised.is_some() { return *memoised.expect("expect memoised is some"); }
-        
-        if self.id == id { return 1; }
+fn path_count(devices: &Graph, from: usize, to: usize, memo: &mut Vec<Option<u64>>) -> u64 {
+    if let Some(count) = memo[from] { return count; }
 
-        let count: u64 = self.outputs
-            .iter()
-            .map(|s| device_map[s].path_count(id, device_map, memo_map))
-            .sum();
+    if from == to { return 1; }
 
-        memo_map.insert(self.id.to_string(), count);
-        count
-    }
-}
\ No newline at end of file
+    let count: u64 = devices
+        .neighbours(from)
+        .iter()
+        .map(|&output| path_count(devices, output, to, memo))
+        .sum();
+
+    memo[from] = Some(count);
+    count
+}
@@ -1,12 +1,14 @@
-use crate::{define_solver, utils::parse::{AocParseExt, IntParser}};
+use crate::{define_solver, utils::parse::{AocParseExt, Diagnostic}, utils::pattern::AocParse};
 
-#[derive(Debug)]
+#[derive(Debug, AocParse)]
+#[aoc(format = "{width}x{height}: {shape_counts}")]
 struct Region {
     width: u32,
     height: u32,
     shape_counts: Vec<u32>,
 }
 
+#[derive(Debug)]
 struct PresentProblems {
     present_sizes: Vec<u32>,
     regions: Vec<Region>
@@ -19,54 +21,25 @@ define_solver!(
     PresentProblems,
     preprocess,
     part_one,
-    part_two
+    part_two,
+    fallible
 );
 
-fn preprocess(input: &str) -> PresentProblems {
-    let mut lines = input.lines();
-
-    let mut present_sizes = vec![];
-    for _ in 0..6 {
-        let _index = lines.next(); 
-        let row_1 = lines.next().unwrap(); 
-        let row_2 = lines.next().unwrap(); 
-        let row_3 = lines.next().unwrap();  
-        let _blank = lines.next(); 
-
-        let row_to_bool = |row: &str| row.split("")
-            .filter(|&c| c.len() > 0)
-            .map(|c| c == "#")
-            .collect();
+fn preprocess(input: &str) -> Result<PresentProblems, Box<Diagnostic>> {
+    let sections: Vec<_> = input.sections().collect();
+    let Some((regions, shapes)) = sections.split_last() else {
+        return Err(Diagnostic::at_line(input, 1, 1, "Expected shapes followed by regions").into());
+    };
 
-        let shape: Vec<Vec<_>> = vec![
-            row_to_bool(row_1),
-            row_to_bool(row_2),
-            row_to_bool(row_3),
-        ];
-
-        let mut present_size = 0;
-        for row in &shape {
-            for cell in row {
-                if *cell {
-                    present_size += 1;
-                }
-            }
-        }
-
-        present_sizes.push(present_size);
-    }
-
-    let regions: Vec<_> = lines
-        .map(|l| {
-            let mut line_parser: IntParser<u32> = l.as_unsigned_iter();
-            let width: u32 = line_parser.next().unwrap();
-            let height: u32 = line_parser.next().unwrap();
-            let shape_counts: Vec<u32> = line_parser.collect();
-            Region { width, height, shape_counts }
-        })
+    // Each shape is its index followed by its rows, so its size is just the count of '#'s.
+    let present_sizes = shapes
+        .iter()
+        .map(|shape| shape.text.bytes().filter(|&b| b == b'#').count() as u32)
         .collect();
 
-    PresentProblems { present_sizes, regions }
+    let regions = regions.parse_records::<Region>()?;
+
+    Ok(PresentProblems { present_sizes, regions })
 }
 
 fn part_one(input: &PresentProblems) -> String {
@@ -9,4 +9,21 @@ pub mod day_08;
 pub mod day_09;
 pub mod day_10;
 pub mod day_11;
-pub mod day_12;
\ No newline at end of file
+pub mod day_12;
+
+use crate::utils::solver::SolverDyn;
+
+pub const SOLVERS: &[&dyn SolverDyn] = &[
+    &day_01::Day1Solver,
+    &day_02::Day2Solver,
+    &day_03::Day3Solver,
+    &day_04::Day4Solver,
+    &day_05::Day5Solver,
+    &day_06::Day6Solver,
+    &day_07::Day7Solver,
+    &day_08::Day8Solver,
+    &day_09::Day9Solver,
+    &day_10::Day10Solver,
+    &day_11::Day11Solver,
+    &day_12::Day12Solver,
+];
@@ -0,0 +1,40 @@
+//!
+//! Isolated runs, which need a binary that runs the child side, so they use the `advent`
+//! binary itself.
+//!
+
+#![cfg(feature = "year2025")]
+
+use std::path::PathBuf;
+
+use advent::{run, RunError, RunOptions};
+
+fn isolated() -> RunOptions {
+    RunOptions { isolate: true, child_binary: Some(PathBuf::from(env!("CARGO_BIN_EXE_advent"))), ..RunOptions::default() }
+}
+
+#[test]
+fn run_isolated() {
+    let options = RunOptions { trace: true, ..isolated() };
+    let result = run("2025", "10", "[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}", &options).unwrap();
+    assert_eq!((result.year.as_str(), result.day.as_str()), ("2025", "10"));
+    assert_eq!((result.solution.one.as_str(), result.solution.two.as_str()), ("2", "10"));
+    assert!(result.events.iter().any(|e| e.name == "pivot"));
+
+    let result = run("2025", "07", "..S..\n..x..\n", &options);
+    assert!(matches!(result, Err(RunError::InvalidInput { .. })));
+
+    let result = run("2025", "02", "11\n", &options);
+    assert!(matches!(result, Err(RunError::Panicked { .. })));
+}
+
+#[test]
+fn run_isolated_stack_overflow() {
+    // Day 11 recurses once per device along a path, so a long chain overflows a tiny stack,
+    // which would abort this process if the solver were not running in a child.
+    let mut input: String = (0..20_000).map(|i| format!("d{}: d{}\n", i, i + 1)).collect();
+    input.push_str("you: d0\nsvr: fft\nfft: dac\ndac: d0\nd20000: out\n");
+    let options = RunOptions { stack_size: Some(64 * 1024), ..isolated() };
+    let result = run("2025", "11", &input, &options);
+    assert_eq!(result, Err(RunError::StackOverflow { year: "2025".to_string(), day: "11".to_string() }));
+}